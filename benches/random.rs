@@ -1,24 +1,456 @@
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+use std::sync::{Barrier, Mutex};
+use std::time::{Duration, Instant};
+
+use criterion::measurement::WallTime;
+use criterion::{
+    criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion, Throughput,
+};
+use prng_benchmark::atomic::*;
+use prng_benchmark::distributions::*;
 use prng_benchmark::*;
 
+#[path = "../tests/common/generators.rs"]
+mod generators;
+
+use generators::for_each_generator;
+
 pub fn scalar(c: &mut Criterion) {
     let mut group = c.benchmark_group("scalar");
+    for_each_generator!(|id, R| scalar_for::<R>(&mut group, id));
+    group.finish()
+}
+
+fn scalar_for<R: Random + Default>(group: &mut BenchmarkGroup<WallTime>, name: &str) {
+    let rng = R::default();
+    let mut state: u64 = 0;
+    group.bench_function(BenchmarkId::new(name, "u64"), |b| {
+        b.iter(|| {
+            let x = rng.u64();
+            state = state.wrapping_add(x);
+        })
+    });
+    black_box(state);
+}
+
+pub fn scalar_u32(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scalar_u32");
+    for_each_generator!(|id, R| scalar_u32_for::<R>(&mut group, id));
+    group.finish()
+}
+
+/// `Widen` passes `u32()` on to the 32-bit generator, so they are measured natively.
+fn scalar_u32_for<R: Random + Default>(group: &mut BenchmarkGroup<WallTime>, name: &str) {
+    let rng = R::default();
+    let mut state: u32 = 0;
+    group.bench_function(BenchmarkId::new(name, "u32"), |b| {
+        b.iter(|| {
+            let x = rng.u32();
+            state = state.wrapping_add(x);
+        })
+    });
+    black_box(state);
+}
+
+/// Number of generator instances the latency group picks from. Must be a power of two.
+const LATENCY_LANES: usize = 8;
+
+/// Measures the serial cost of a call: every output selects the instance used for the next call,
+/// so the next call can't start before the previous one has finished.
+pub fn latency(c: &mut Criterion) {
+    let mut group = c.benchmark_group("latency");
+    for_each_generator!(|id, R| latency_for::<R>(&mut group, id));
+    group.finish()
+}
+
+fn latency_for<R: Random + Default>(group: &mut BenchmarkGroup<WallTime>, name: &str) {
+    let lanes: [R; LATENCY_LANES] = std::array::from_fn(|_| R::default());
+    let mut index = 0;
+    group.bench_function(BenchmarkId::new(name, "u64"), |b| {
+        b.iter(|| {
+            let x = lanes[index].u64();
+            index = (x >> (64 - LATENCY_LANES.trailing_zeros())) as usize;
+            black_box(x)
+        })
+    });
+}
+
+const SHUFFLE_SMALL: usize = 1 << 10;
+const SHUFFLE_LARGE: usize = 1 << 20;
+const MONTE_CARLO_SAMPLES: usize = 1 << 10;
+const LOOKUPS: usize = 1 << 10;
+const DICE_ROLLS: usize = 1 << 10;
+
+/// Table sizes in `u64` words: 16 KiB fit into L1, 512 KiB into L2 and 64 MiB only into DRAM.
+const LOOKUP_TABLES: [(&str, usize); 3] = [
+    ("lookup_l1", 16 << 7),
+    ("lookup_l2", 512 << 7),
+    ("lookup_dram", 64 << 17),
+];
+
+/// Runs the generator inside small, but realistic algorithms.
+pub fn workloads(c: &mut Criterion) {
+    let mut group = c.benchmark_group("workloads");
+
+    let tables: Vec<(&str, Vec<u64>)> = LOOKUP_TABLES
+        .iter()
+        .map(|&(name, len)| (name, (0..len as u64).collect()))
+        .collect();
+
+    for_each_generator!(|id, R| workload(&mut group, id, &R::default(), &tables));
+
+    group.finish()
+}
+
+fn workload<R: Random>(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    rng: &R,
+    tables: &[(&str, Vec<u64>)],
+) {
+    for (parameter, len) in [("shuffle_1k", SHUFFLE_SMALL), ("shuffle_1m", SHUFFLE_LARGE)] {
+        let mut data: Vec<u32> = (0..len as u32).collect();
+        group.throughput(Throughput::Elements(len as u64));
+        group.bench_function(BenchmarkId::new(name, parameter), |b| {
+            b.iter(|| {
+                // Fisher-Yates
+                for i in (1..data.len()).rev() {
+                    let j = rng.bounded_u64(i as u64 + 1) as usize;
+                    data.swap(i, j);
+                }
+            })
+        });
+        black_box(&data);
+    }
+
+    group.throughput(Throughput::Elements(MONTE_CARLO_SAMPLES as u64));
+    group.bench_function(BenchmarkId::new(name, "monte_carlo_pi"), |b| {
+        b.iter(|| {
+            let mut inside = 0u32;
+            for _ in 0..MONTE_CARLO_SAMPLES {
+                let x = rng.f64();
+                let y = rng.f64();
+                inside += (x * x + y * y < 1.0) as u32;
+            }
+            black_box(4.0 * inside as f64 / MONTE_CARLO_SAMPLES as f64)
+        })
+    });
+
+    for (parameter, table) in tables {
+        let mask = table.len() - 1;
+        group.throughput(Throughput::Elements(LOOKUPS as u64));
+        group.bench_function(BenchmarkId::new(name, parameter), |b| {
+            b.iter(|| {
+                let mut sum = 0u64;
+                for _ in 0..LOOKUPS {
+                    sum = sum.wrapping_add(table[rng.u64() as usize & mask]);
+                }
+                black_box(sum)
+            })
+        });
+    }
+
+    group.throughput(Throughput::Elements(DICE_ROLLS as u64));
+    group.bench_function(BenchmarkId::new(name, "dice_rolls"), |b| {
+        b.iter(|| {
+            let mut sum = 0u64;
+            for _ in 0..DICE_ROLLS {
+                sum += rng.bounded_u64(6) + 1;
+            }
+            black_box(sum)
+        })
+    });
+}
+
+/// Measures how expensive it is to get a ready-to-use generator.
+pub fn construction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("construction");
+
+    for_each_generator!(|id, R| construction_for::<R>(&mut group, id));
+
+    first_access(&mut group, "lehmer64", lehmer64_u64);
+    first_access(&mut group, "mwc256xxa64", mwc256xxa64_u64);
+    first_access(&mut group, "mwc128", mwc128_u64);
+    first_access(&mut group, "mwc192", mwc192_u64);
+    first_access(&mut group, "mwc256", mwc256_u64);
+    first_access(&mut group, "mwc128xxa32", mwc128xxa32_u64);
+    first_access(&mut group, "pcg64fast", pcg64fast_u64);
+    first_access(&mut group, "pcg64", pcg64_u64);
+    first_access(&mut group, "pcg64dxsm", pcg64dxsm_u64);
+    first_access(&mut group, "pcg64dxsmfast", pcg64dxsmfast_u64);
+    first_access(&mut group, "splitmix64", splitmix64_u64);
+    first_access(&mut group, "sfc64", sfc64_u64);
+    first_access(&mut group, "jsf64", jsf64_u64);
+    first_access(&mut group, "romu_jr", romu_jr_u64);
+    first_access(&mut group, "romu_trio", romu_trio_u64);
+    first_access(&mut group, "romu_quad", romu_quad_u64);
+    first_access(&mut group, "romu_duo", romu_duo_u64);
+    first_access(&mut group, "tylo64", tylo64_u64);
+    first_access(&mut group, "wyrand", wyrand_u64);
+    first_access(&mut group, "xoshiro256++", xoshiro256plusplus_u64);
+    first_access(&mut group, "xoshiro256**", xoshiro256starstar_u64);
+    first_access(&mut group, "xoshiro256+", xoshiro256plus_u64);
+    first_access(&mut group, "xoshiro512++", xoshiro512plusplus_u64);
+    first_access(&mut group, "xoroshiro128++", xoroshiro128plusplus_u64);
+    first_access(&mut group, "xoroshiro128**", xoroshiro128starstar_u64);
+    first_access(&mut group, "xoroshiro128+", xoroshiro128plus_u64);
+    first_access(&mut group, "xoroshiro1024++", xoroshiro1024plusplus_u64);
+    first_access(&mut group, "chacha8", chacha8_u64);
+    first_access(&mut group, "chacha12", chacha12_u64);
+    first_access(&mut group, "chacha20", chacha20_u64);
+    first_access(&mut group, "mt19937_64", mt19937_64_u64);
+    first_access(&mut group, "xorshift128+", xorshift128plus_u64);
+
+    group.finish()
+}
+
+fn construction_for<R: SeedableRandom + Default>(group: &mut BenchmarkGroup<WallTime>, name: &str) {
+    group.bench_function(BenchmarkId::new(name, "default"), |b| b.iter(R::default));
+
+    let rng = R::default();
+    group.bench_function(BenchmarkId::new(name, "seed"), |b| b.iter(|| rng.seed()));
+
+    let mut seed = 0u64;
+    group.bench_function(BenchmarkId::new(name, "seed_from_u64"), |b| {
+        b.iter(|| {
+            seed = seed.wrapping_add(1);
+            rng.seed_from_u64(black_box(seed));
+            black_box(&rng);
+        })
+    });
+}
+
+/// Measures the first call of a thread local accessor on a freshly spawned thread,
+/// which includes the lazy initialization. Spawning the thread isn't measured.
+fn first_access(group: &mut BenchmarkGroup<WallTime>, name: &str, accessor: fn() -> u64) {
+    group.bench_function(BenchmarkId::new(name, "tls_first_access"), |b| {
+        b.iter_custom(|iters| {
+            (0..iters)
+                .map(|_| {
+                    std::thread::spawn(move || {
+                        let start = Instant::now();
+                        black_box(accessor());
+                        start.elapsed()
+                    })
+                    .join()
+                    .unwrap()
+                })
+                .sum::<Duration>()
+        })
+    });
+}
+
+const DISTRIBUTION_SAMPLES: usize = 1 << 10;
+
+/// Measures the cost of non-uniform variates on top of each generator.
+pub fn distributions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("distributions");
+    group.throughput(Throughput::Elements(DISTRIBUTION_SAMPLES as u64));
+
+    for_each_generator!(|id, R| distributions_for(&mut group, id, &R::default()));
+
+    group.finish()
+}
 
-    let lehmer64 = Lehmer64::default();
-    let mwc256xxa64 = Mwc256XXA64::default();
-    let pcg64 = Pcg64::default();
-    let pcg64fast = Pcg64Fast::default();
-    let tylo64 = Tylo64::default();
-    let romu_jr = RomuJr::default();
-    let romu_trio = RomuTrio::default();
-    let splitmix64 = Splitmix64::default();
-    let wyrand = Wyrand::default();
-    let xoshiro256pp = Xoshiro256plusplus::default();
+fn distributions_for<R: Random>(group: &mut BenchmarkGroup<WallTime>, name: &str, rng: &R) {
+    sample_batch(group, name, "normal", rng, &StandardNormal);
+    sample_batch(group, name, "exp", rng, &Exp1);
+    sample_batch(group, name, "gamma", rng, &Gamma::new(2.5, 1.0).unwrap());
+    sample_batch(group, name, "beta", rng, &Beta::new(2.0, 3.0).unwrap());
+    sample_batch(
+        group,
+        name,
+        "lognormal",
+        rng,
+        &LogNormal::new(0.0, 1.0).unwrap(),
+    );
+    sample_batch(group, name, "cauchy", rng, &Cauchy::new(0.0, 1.0).unwrap());
+}
+
+fn sample_batch<R: Random, D: Distribution<f64>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    parameter: &str,
+    rng: &R,
+    distribution: &D,
+) {
+    group.bench_function(BenchmarkId::new(name, parameter), |b| {
+        b.iter(|| {
+            let mut sum = 0.0;
+            for _ in 0..DISTRIBUTION_SAMPLES {
+                sum += distribution.sample(rng);
+            }
+            black_box(sum)
+        })
+    });
+}
+
+const WEIGHTED_SAMPLES: usize = 1 << 10;
+
+/// Compares the alias table with the binary search over cumulative weights,
+/// with few and with many categories.
+pub fn weighted(c: &mut Criterion) {
+    let mut group = c.benchmark_group("weighted");
+    group.throughput(Throughput::Elements(WEIGHTED_SAMPLES as u64));
+
+    for_each_generator!(|id, R| weighted_for(&mut group, id, &R::default()));
+
+    group.finish()
+}
+
+fn weighted_for<R: Random>(group: &mut BenchmarkGroup<WallTime>, name: &str, rng: &R) {
+    for categories in [16, 4096] {
+        // Zipf-like weights, so a few categories dominate.
+        let weights: Vec<f64> = (1..=categories).map(|i| 1.0 / i as f64).collect();
+        index_batch(
+            group,
+            name,
+            &format!("alias/{categories}"),
+            rng,
+            &AliasTable::new(&weights).unwrap(),
+        );
+        index_batch(
+            group,
+            name,
+            &format!("cumulative/{categories}"),
+            rng,
+            &CumulativeWeights::new(&weights).unwrap(),
+        );
+    }
+}
+
+fn index_batch<R: Random, D: Distribution<usize>>(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    parameter: &str,
+    rng: &R,
+    distribution: &D,
+) {
+    group.bench_function(BenchmarkId::new(name, parameter), |b| {
+        b.iter(|| {
+            let mut sum = 0;
+            for _ in 0..WEIGHTED_SAMPLES {
+                sum += distribution.sample(rng);
+            }
+            black_box(sum)
+        })
+    });
+}
+
+const FLIPS: usize = 1 << 10;
+
+/// Compares taking a whole output per coin flip or die roll with taking
+/// only the bits needed from a `BitBuffer`.
+pub fn bits(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bits");
+    group.throughput(Throughput::Elements(FLIPS as u64));
+
+    for_each_generator!(|id, R| bits_for(&mut group, id, R::default()));
+
+    group.finish()
+}
+
+fn bits_for<R: Random>(group: &mut BenchmarkGroup<WallTime>, name: &str, rng: R) {
+    group.bench_function(BenchmarkId::new(name, "bool/u64"), |b| {
+        b.iter(|| {
+            let mut heads = 0;
+            for _ in 0..FLIPS {
+                heads += (rng.u64() >> 63) as usize;
+            }
+            black_box(heads)
+        })
+    });
+    group.bench_function(BenchmarkId::new(name, "d6/bounded_u64"), |b| {
+        b.iter(|| {
+            let mut sum = 0;
+            for _ in 0..FLIPS {
+                sum += rng.bounded_u64(6);
+            }
+            black_box(sum)
+        })
+    });
+
+    let buffer = BitBuffer::new(rng);
+    group.bench_function(BenchmarkId::new(name, "bool/buffered"), |b| {
+        b.iter(|| {
+            let mut heads = 0;
+            for _ in 0..FLIPS {
+                heads += buffer.bool() as usize;
+            }
+            black_box(heads)
+        })
+    });
+    group.bench_function(BenchmarkId::new(name, "d6/buffered"), |b| {
+        b.iter(|| {
+            let mut sum = 0;
+            for _ in 0..FLIPS {
+                sum += buffer.bounded(6);
+            }
+            black_box(sum)
+        })
+    });
+}
+
+pub fn bytes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bytes");
+    for_each_generator!(|id, R| bytes_for::<R>(&mut group, id));
+    group.finish();
+}
+
+fn bytes_for<R: Random + Default>(group: &mut BenchmarkGroup<WallTime>, name: &str) {
+    let count = 1024 * 1024;
+    group.throughput(Throughput::Bytes(count));
+
+    let rng = R::default();
+    let mut buffer = vec![0u8; count as usize];
+    group.bench_function(BenchmarkId::new(name, "1MiB"), |b| {
+        b.iter(|| {
+            rng.fill_bytes(&mut buffer);
+        })
+    });
+    black_box(buffer);
+}
+
+pub fn thread_local(c: &mut Criterion) {
+    let mut group = c.benchmark_group("thread_local");
+
+    lehmer64_seed();
+    mwc256xxa64_seed();
+    pcg64_seed();
+    pcg64fast_seed();
+    tylo64_seed();
+    romu_jr_seed();
+    romu_trio_seed();
+    splitmix64_seed();
+    wyrand_seed();
+    xoshiro256plusplus_seed();
+    pcg64dxsm_seed();
+    pcg64dxsmfast_seed();
+    xoshiro256starstar_seed();
+    xoshiro256plus_seed();
+    xoshiro512plusplus_seed();
+    xoroshiro128plusplus_seed();
+    xoroshiro128starstar_seed();
+    xoroshiro128plus_seed();
+    xoroshiro1024plusplus_seed();
+    romu_quad_seed();
+    romu_duo_seed();
+    sfc64_seed();
+    jsf64_seed();
+    chacha8_seed();
+    chacha12_seed();
+    chacha20_seed();
+    mt19937_64_seed();
+    xorshift128plus_seed();
+    mwc128_seed();
+    mwc192_seed();
+    mwc256_seed();
+    mwc128xxa32_seed();
 
     let mut state: u64 = 0;
     group.bench_function(BenchmarkId::new("lehmer64", "u64"), |b| {
         b.iter(|| {
-            let x = lehmer64.u64();
+            let x = lehmer64_u64();
             state = state.wrapping_add(x);
         })
     });
@@ -27,285 +459,277 @@ pub fn scalar(c: &mut Criterion) {
     let mut state: u64 = 0;
     group.bench_function(BenchmarkId::new("mwc256xxa64", "u64"), |b| {
         b.iter(|| {
-            let x = mwc256xxa64.u64();
+            let x = mwc256xxa64_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("pcg64fast", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("mwc128", "u64"), |b| {
         b.iter(|| {
-            let x = pcg64fast.u64();
+            let x = mwc128_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("pcg64", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("mwc192", "u64"), |b| {
         b.iter(|| {
-            let x = pcg64.u64();
+            let x = mwc192_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("splitmix64", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("mwc256", "u64"), |b| {
         b.iter(|| {
-            let x = splitmix64.u64();
+            let x = mwc256_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("romu_jr", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("mwc128xxa32", "u64"), |b| {
         b.iter(|| {
-            let x = romu_jr.u64();
+            let x = mwc128xxa32_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("romu_trio", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("pcg64fast", "u64"), |b| {
         b.iter(|| {
-            let x = romu_trio.u64();
+            let x = pcg64fast_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("tylo64", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("pcg64", "u64"), |b| {
         b.iter(|| {
-            let x = tylo64.u64();
+            let x = pcg64_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("wyrand", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("pcg64dxsm", "u64"), |b| {
         b.iter(|| {
-            let x = wyrand.u64();
+            let x = pcg64dxsm_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("xoshiro256++", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("pcg64dxsmfast", "u64"), |b| {
         b.iter(|| {
-            let x = xoshiro256pp.u64();
+            let x = pcg64dxsmfast_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
-    group.finish()
-}
-
-pub fn bytes(c: &mut Criterion) {
-    let mut group = c.benchmark_group("bytes");
-    let count = 1024 * 1024;
-    group.throughput(Throughput::Bytes(count));
-
-    let lehmer64 = Lehmer64::default();
-    let mwc256xxa64 = Mwc256XXA64::default();
-    let pcg64fast = Pcg64Fast::default();
-    let pcg64 = Pcg64::default();
-    let tylo = Tylo64::default();
-    let romu_jr = RomuJr::default();
-    let romu_trio = RomuTrio::default();
-    let splitmix = Splitmix64::default();
-    let wyrand = Wyrand::default();
-    let xoshiro256pp = Xoshiro256plusplus::default();
-
-    let mut buffer = vec![0u8; count as usize];
-    group.bench_function(BenchmarkId::new("lehmer64", "1MiB"), |b| {
+    let mut state: u64 = 0;
+    group.bench_function(BenchmarkId::new("splitmix64", "u64"), |b| {
         b.iter(|| {
-            lehmer64.fill_bytes(&mut buffer);
+            let x = splitmix64_u64();
+            state = state.wrapping_add(x);
         })
     });
-    black_box(buffer);
+    black_box(state);
 
-    let mut buffer = vec![0u8; count as usize];
-    group.bench_function(BenchmarkId::new("mwc256xxa64", "1MiB"), |b| {
+    let mut state: u64 = 0;
+    group.bench_function(BenchmarkId::new("sfc64", "u64"), |b| {
         b.iter(|| {
-            mwc256xxa64.fill_bytes(&mut buffer);
+            let x = sfc64_u64();
+            state = state.wrapping_add(x);
         })
     });
-    black_box(buffer);
+    black_box(state);
 
-    let mut buffer = vec![0u8; count as usize];
-    group.bench_function(BenchmarkId::new("pcg64fast", "1MiB"), |b| {
+    let mut state: u64 = 0;
+    group.bench_function(BenchmarkId::new("jsf64", "u64"), |b| {
         b.iter(|| {
-            pcg64fast.fill_bytes(&mut buffer);
+            let x = jsf64_u64();
+            state = state.wrapping_add(x);
         })
     });
-    black_box(buffer);
+    black_box(state);
 
-    let mut buffer = vec![0u8; count as usize];
-    group.bench_function(BenchmarkId::new("pcg64", "1MiB"), |b| {
+    let mut state: u64 = 0;
+    group.bench_function(BenchmarkId::new("romu_jr", "u64"), |b| {
         b.iter(|| {
-            pcg64.fill_bytes(&mut buffer);
+            let x = romu_jr_u64();
+            state = state.wrapping_add(x);
         })
     });
-    black_box(buffer);
+    black_box(state);
 
-    let mut buffer = vec![0u8; count as usize];
-    group.bench_function(BenchmarkId::new("romu_jr", "1MiB"), |b| {
+    let mut state: u64 = 0;
+    group.bench_function(BenchmarkId::new("romu_trio", "u64"), |b| {
         b.iter(|| {
-            romu_jr.fill_bytes(&mut buffer);
+            let x = romu_trio_u64();
+            state = state.wrapping_add(x);
         })
     });
-    black_box(buffer);
+    black_box(state);
 
-    let mut buffer = vec![0u8; count as usize];
-    group.bench_function(BenchmarkId::new("romu_trio", "1MiB"), |b| {
+    let mut state: u64 = 0;
+    group.bench_function(BenchmarkId::new("romu_quad", "u64"), |b| {
         b.iter(|| {
-            romu_trio.fill_bytes(&mut buffer);
+            let x = romu_quad_u64();
+            state = state.wrapping_add(x);
         })
     });
-    black_box(buffer);
+    black_box(state);
 
-    let mut buffer = vec![0u8; count as usize];
-    group.bench_function(BenchmarkId::new("splitmix64", "1MiB"), |b| {
+    let mut state: u64 = 0;
+    group.bench_function(BenchmarkId::new("romu_duo", "u64"), |b| {
         b.iter(|| {
-            splitmix.fill_bytes(&mut buffer);
+            let x = romu_duo_u64();
+            state = state.wrapping_add(x);
         })
     });
-    black_box(buffer);
+    black_box(state);
 
-    let mut buffer = vec![0u8; count as usize];
+    let mut state: u64 = 0;
     group.bench_function(BenchmarkId::new("tylo64", "u64"), |b| {
         b.iter(|| {
-            tylo.fill_bytes(&mut buffer);
+            let x = tylo64_u64();
+            state = state.wrapping_add(x);
         })
     });
-    black_box(buffer);
+    black_box(state);
 
-    let mut buffer = vec![0u8; count as usize];
-    group.bench_function(BenchmarkId::new("wyrand", "1MiB"), |b| {
+    let mut state: u64 = 0;
+    group.bench_function(BenchmarkId::new("wyrand", "u64"), |b| {
         b.iter(|| {
-            wyrand.fill_bytes(&mut buffer);
+            let x = wyrand_u64();
+            state = state.wrapping_add(x);
         })
     });
-    black_box(buffer);
+    black_box(state);
 
-    let mut buffer = vec![0u8; count as usize];
-    group.bench_function(BenchmarkId::new("xoshiro256++", "1MiB"), |b| {
+    let mut state: u64 = 0;
+    group.bench_function(BenchmarkId::new("xoshiro256++", "u64"), |b| {
         b.iter(|| {
-            xoshiro256pp.fill_bytes(&mut buffer);
+            let x = xoshiro256plusplus_u64();
+            state = state.wrapping_add(x);
         })
     });
-    black_box(buffer);
-
-    group.finish();
-}
+    black_box(state);
 
-pub fn thread_local(c: &mut Criterion) {
-    let mut group = c.benchmark_group("thread_local");
+    let mut state: u64 = 0;
+    group.bench_function(BenchmarkId::new("xoshiro256**", "u64"), |b| {
+        b.iter(|| {
+            let x = xoshiro256starstar_u64();
+            state = state.wrapping_add(x);
+        })
+    });
+    black_box(state);
 
-    lehmer64_seed();
-    mwc256xxa64_seed();
-    pcg64_seed();
-    pcg64fast_seed();
-    tylo64_seed();
-    romu_jr_seed();
-    romu_trio_seed();
-    splitmix64_seed();
-    wyrand_seed();
-    xoshiro256plusplus_seed();
+    let mut state: u64 = 0;
+    group.bench_function(BenchmarkId::new("xoshiro256+", "u64"), |b| {
+        b.iter(|| {
+            let x = xoshiro256plus_u64();
+            state = state.wrapping_add(x);
+        })
+    });
+    black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("lehmer64", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("xoshiro512++", "u64"), |b| {
         b.iter(|| {
-            let x = lehmer64_u64();
+            let x = xoshiro512plusplus_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("mwc256xxa64", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("xoroshiro128++", "u64"), |b| {
         b.iter(|| {
-            let x = mwc256xxa64_u64();
+            let x = xoroshiro128plusplus_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("pcg64fast", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("xoroshiro128**", "u64"), |b| {
         b.iter(|| {
-            let x = pcg64fast_u64();
+            let x = xoroshiro128starstar_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("pcg64", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("xoroshiro128+", "u64"), |b| {
         b.iter(|| {
-            let x = pcg64_u64();
+            let x = xoroshiro128plus_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("splitmix64", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("xoroshiro1024++", "u64"), |b| {
         b.iter(|| {
-            let x = splitmix64_u64();
+            let x = xoroshiro1024plusplus_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("romu_jr", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("chacha8", "u64"), |b| {
         b.iter(|| {
-            let x = romu_jr_u64();
+            let x = chacha8_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("romu_trio", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("chacha12", "u64"), |b| {
         b.iter(|| {
-            let x = romu_trio_u64();
+            let x = chacha12_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("tylo64", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("chacha20", "u64"), |b| {
         b.iter(|| {
-            let x = tylo64_u64();
+            let x = chacha20_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("wyrand", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("mt19937_64", "u64"), |b| {
         b.iter(|| {
-            let x = wyrand_u64();
+            let x = mt19937_64_u64();
             state = state.wrapping_add(x);
         })
     });
     black_box(state);
 
     let mut state: u64 = 0;
-    group.bench_function(BenchmarkId::new("xoshiro256++", "u64"), |b| {
+    group.bench_function(BenchmarkId::new("xorshift128+", "u64"), |b| {
         b.iter(|| {
-            let x = xoshiro256plusplus_u64();
+            let x = xorshift128plus_u64();
             state = state.wrapping_add(x);
         })
     });
@@ -314,5 +738,85 @@ pub fn thread_local(c: &mut Criterion) {
     group.finish()
 }
 
-criterion_group!(benches, scalar, bytes, thread_local);
+/// Calls `next` `iters` times on each of `threads` threads at once and returns the
+/// time until the last one finished. Spawning the threads isn't measured, and
+/// thread locals are initialized before.
+fn contended(threads: usize, iters: u64, next: &(impl Fn() -> u64 + Sync)) -> Duration {
+    let barrier = Barrier::new(threads);
+    std::thread::scope(|s| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut state = next();
+                    barrier.wait();
+                    let start = Instant::now();
+                    for _ in 0..iters {
+                        state = state.wrapping_add(next());
+                    }
+                    let elapsed = start.elapsed();
+                    black_box(state);
+                    elapsed
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .max()
+            .unwrap()
+    })
+}
+
+/// Compares generators shared by all threads against a generator per thread, with
+/// every thread calling them in a tight loop. The throughput counts the outputs of
+/// all threads.
+pub fn contention(c: &mut Criterion) {
+    let mut group = c.benchmark_group("contention");
+
+    let atomic_wyrand = AtomicWyrand::from_seed(1);
+    let atomic_splitmix64 = AtomicSplitmix64::from_seed(1);
+    let shared = Shared::new(Xoshiro256plusplus::from_seed(1));
+    let mutex = Mutex::new(Xoshiro256plusplus::from_seed(1));
+
+    for threads in [1, 2, 4, 8] {
+        group.throughput(Throughput::Elements(threads as u64));
+        let id = |name| BenchmarkId::new(name, format!("{threads}_threads"));
+
+        group.bench_function(id("atomic_wyrand"), |b| {
+            b.iter_custom(|iters| contended(threads, iters, &|| atomic_wyrand.u64()))
+        });
+        group.bench_function(id("atomic_splitmix64"), |b| {
+            b.iter_custom(|iters| contended(threads, iters, &|| atomic_splitmix64.u64()))
+        });
+        group.bench_function(id("shared_xoshiro256++"), |b| {
+            b.iter_custom(|iters| contended(threads, iters, &|| shared.u64()))
+        });
+        group.bench_function(id("mutex_xoshiro256++"), |b| {
+            b.iter_custom(|iters| contended(threads, iters, &|| mutex.lock().unwrap().u64()))
+        });
+        group.bench_function(id("tls_wyrand"), |b| {
+            b.iter_custom(|iters| contended(threads, iters, &wyrand_u64))
+        });
+        group.bench_function(id("tls_xoshiro256++"), |b| {
+            b.iter_custom(|iters| contended(threads, iters, &xoshiro256plusplus_u64))
+        });
+    }
+
+    group.finish()
+}
+
+criterion_group!(
+    benches,
+    scalar,
+    scalar_u32,
+    latency,
+    workloads,
+    construction,
+    distributions,
+    weighted,
+    bits,
+    bytes,
+    thread_local,
+    contention
+);
 criterion_main!(benches);
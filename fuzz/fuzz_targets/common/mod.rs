@@ -0,0 +1,467 @@
+//! Builds generators from arbitrary states and checks the invariants their
+//! fields document.
+
+use std::cell::Cell;
+
+use prng_benchmark::*;
+
+/// Returns the little-endian words in `bytes`, followed by zeros once they run
+/// out, so that any input can be turned into any state.
+pub fn words(bytes: &[u8]) -> impl FnMut() -> u64 + '_ {
+    let mut chunks = bytes.chunks(8);
+    move || {
+        let mut word = [0; 8];
+        if let Some(chunk) = chunks.next() {
+            word[..chunk.len()].copy_from_slice(chunk);
+        }
+        u64::from_le_bytes(word)
+    }
+}
+
+/// Generators that can be built from raw words, bypassing `seed_from()`.
+pub trait Raw: SeedableRandom + Sized {
+    /// Builds the generator from `next`, keeping only the invariants whose
+    /// violation panics, like indices into a buffer.
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self;
+
+    /// Checks the invariants `seed_from()` establishes, which running the
+    /// generator has to preserve. Generators in other states can get stuck on
+    /// a constant output.
+    fn is_valid(&self) -> bool {
+        true
+    }
+}
+
+fn cell<T>(x: T) -> Cell<T> {
+    Cell::new(x)
+}
+
+fn u128_from(next: &mut impl FnMut() -> u64) -> u128 {
+    ((next() as u128) << 64) + next() as u128
+}
+
+impl Raw for Tylo64 {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            a: cell(next()),
+            b: cell(next()),
+            w: cell(next()),
+            k: cell(next()),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.k.get() % 2 == 1
+    }
+}
+
+impl Raw for RomuJr {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            x: cell(next()),
+            y: cell(next()),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.x.get() != 0 || self.y.get() != 0
+    }
+}
+
+impl Raw for RomuTrio {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            x: cell(next()),
+            y: cell(next()),
+            z: cell(next()),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        [&self.x, &self.y, &self.z].iter().any(|s| s.get() != 0)
+    }
+}
+
+impl Raw for RomuQuad {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            w: cell(next()),
+            x: cell(next()),
+            y: cell(next()),
+            z: cell(next()),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        [&self.w, &self.x, &self.y, &self.z]
+            .iter()
+            .any(|s| s.get() != 0)
+    }
+}
+
+impl Raw for RomuDuo {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            x: cell(next()),
+            y: cell(next()),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.x.get() != 0 || self.y.get() != 0
+    }
+}
+
+impl Raw for Sfc64 {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            a: cell(next()),
+            b: cell(next()),
+            c: cell(next()),
+            counter: cell(next()),
+        }
+    }
+}
+
+impl Raw for Jsf64 {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            a: cell(next()),
+            b: cell(next()),
+            c: cell(next()),
+            d: cell(next()),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        [&self.a, &self.b, &self.c, &self.d]
+            .iter()
+            .any(|s| s.get() != 0)
+    }
+}
+
+impl Raw for Lehmer64 {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            state: cell(u128_from(next)),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.state.get() % 2 == 1
+    }
+}
+
+/// Checks that `mwc` is not on one of the two fixed points, all zero or all
+/// words at their maximum with a carry of `A - 1`. Other states with that carry
+/// are on the cycle and do come up, even though `is_valid()` rejects them as
+/// seeds.
+fn mwc_on_cycle<const LAG: usize, const A: u64>(mwc: &Mwc<LAG, A>) -> bool {
+    let c = mwc.c.get();
+    let x = mwc.x.each_ref().map(Cell::get);
+    c < A && !(c == 0 && x == [0; LAG]) && !(c == A - 1 && x == [u64::MAX; LAG])
+}
+
+fn mwc32_on_cycle<const LAG: usize, const A: u32>(mwc: &Mwc32<LAG, A>) -> bool {
+    let c = mwc.c.get();
+    let x = mwc.x.each_ref().map(Cell::get);
+    c < A && !(c == 0 && x == [0; LAG]) && !(c == A - 1 && x == [u32::MAX; LAG])
+}
+
+macro_rules! raw_mwc {
+    ($name:ident) => {
+        impl Raw for $name {
+            fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+                let s = Self::default();
+                s.mwc.x.iter().for_each(|x| x.set(next() as _));
+                s.mwc.c.set(next() as _);
+                s
+            }
+
+            fn is_valid(&self) -> bool {
+                mwc_on_cycle(&self.mwc)
+            }
+        }
+    };
+}
+
+raw_mwc!(Mwc128);
+raw_mwc!(Mwc192);
+raw_mwc!(Mwc256);
+raw_mwc!(Mwc256XXA64);
+
+impl Raw for Wyrand {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            state: cell(next()),
+        }
+    }
+}
+
+impl Raw for Splitmix64 {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            state: cell(next()),
+        }
+    }
+}
+
+macro_rules! raw_xoshiro {
+    ($name:ident) => {
+        impl Raw for $name {
+            fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+                Self {
+                    s0: cell(next()),
+                    s1: cell(next()),
+                    s2: cell(next()),
+                    s3: cell(next()),
+                }
+            }
+
+            fn is_valid(&self) -> bool {
+                [&self.s0, &self.s1, &self.s2, &self.s3]
+                    .iter()
+                    .any(|s| s.get() != 0)
+            }
+        }
+    };
+}
+
+raw_xoshiro!(Xoshiro256plusplus);
+raw_xoshiro!(Xoshiro256starstar);
+raw_xoshiro!(Xoshiro256plus);
+
+macro_rules! raw_xoroshiro {
+    ($name:ident) => {
+        impl Raw for $name {
+            fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+                Self {
+                    s0: cell(next()),
+                    s1: cell(next()),
+                }
+            }
+
+            fn is_valid(&self) -> bool {
+                self.s0.get() != 0 || self.s1.get() != 0
+            }
+        }
+    };
+}
+
+raw_xoroshiro!(Xoroshiro128plusplus);
+raw_xoroshiro!(Xoroshiro128starstar);
+raw_xoroshiro!(Xoroshiro128plus);
+raw_xoroshiro!(Xorshift128plus);
+
+impl Raw for Xoshiro512plusplus {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            s: std::array::from_fn(|_| cell(next())),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.s.iter().any(|s| s.get() != 0)
+    }
+}
+
+impl Raw for Xoroshiro1024plusplus {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            s: std::array::from_fn(|_| cell(next())),
+            p: cell(next() as usize % 16),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.p.get() < 16 && self.s.iter().any(|s| s.get() != 0)
+    }
+}
+
+impl Raw for Pcg64 {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            state: cell(u128_from(next)),
+            inc: cell(u128_from(next)),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.inc.get() % 2 == 1
+    }
+}
+
+impl Raw for Pcg64Fast {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            state: cell(u128_from(next)),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.state.get() % 2 == 1
+    }
+}
+
+impl Raw for Pcg64Dxsm {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            state: cell(u128_from(next)),
+            inc: cell(u128_from(next)),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.inc.get() % 2 == 1
+    }
+}
+
+impl Raw for Pcg64DxsmFast {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            state: cell(u128_from(next)),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        self.state.get() % 2 == 1
+    }
+}
+
+impl<const ROUNDS: usize> Raw for ChaCha<ROUNDS> {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            state: std::array::from_fn(|_| cell(next() as u32)),
+            buffer: std::array::from_fn(|_| cell(next() as u32)),
+            index: cell(next() as usize),
+        }
+    }
+}
+
+impl Raw for Mt19937_64 {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self {
+            mt: std::array::from_fn(|_| cell(next())),
+            index: cell(next() as usize),
+        }
+    }
+
+    fn is_valid(&self) -> bool {
+        // The twist ignores the lower bits of the first word.
+        self.mt[0].get() >> 63 != 0 || self.mt[1..].iter().any(|s| s.get() != 0)
+    }
+}
+
+impl Raw for Widen<Mwc128XXA32> {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        let s = Self::default();
+        s.0.mwc.x.iter().for_each(|x| x.set(next() as u32));
+        s.0.mwc.c.set(next() as u32);
+        s
+    }
+
+    fn is_valid(&self) -> bool {
+        mwc32_on_cycle(&self.0.mwc)
+    }
+}
+
+impl Raw for Widen<Pcg32> {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self(Pcg32 {
+            state: cell(next()),
+            inc: cell(next()),
+        })
+    }
+
+    fn is_valid(&self) -> bool {
+        self.0.inc.get() % 2 == 1
+    }
+}
+
+impl Raw for Widen<RomuTrio32> {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self(RomuTrio32 {
+            x: cell(next() as u32),
+            y: cell(next() as u32),
+            z: cell(next() as u32),
+        })
+    }
+
+    fn is_valid(&self) -> bool {
+        let s = &self.0;
+        [&s.x, &s.y, &s.z].iter().any(|s| s.get() != 0)
+    }
+}
+
+impl Raw for Widen<Sfc32> {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self(Sfc32 {
+            a: cell(next() as u32),
+            b: cell(next() as u32),
+            c: cell(next() as u32),
+            counter: cell(next() as u32),
+        })
+    }
+}
+
+impl Raw for Widen<Xoshiro128plusplus> {
+    fn from_raw(next: &mut impl FnMut() -> u64) -> Self {
+        Self(Xoshiro128plusplus {
+            s0: cell(next() as u32),
+            s1: cell(next() as u32),
+            s2: cell(next() as u32),
+            s3: cell(next() as u32),
+        })
+    }
+
+    fn is_valid(&self) -> bool {
+        let s = &self.0;
+        [&s.s0, &s.s1, &s.s2, &s.s3].iter().any(|s| s.get() != 0)
+    }
+}
+
+/// Expands to a slice with `$f` instantiated for every generator.
+macro_rules! generators {
+    ($f:ident) => {
+        &[
+            $f::<ChaCha8>,
+            $f::<ChaCha12>,
+            $f::<ChaCha20>,
+            $f::<Jsf64>,
+            $f::<Lehmer64>,
+            $f::<Mt19937_64>,
+            $f::<Mwc128>,
+            $f::<Mwc192>,
+            $f::<Mwc256>,
+            $f::<Mwc256XXA64>,
+            $f::<Pcg64>,
+            $f::<Pcg64Fast>,
+            $f::<Pcg64Dxsm>,
+            $f::<Pcg64DxsmFast>,
+            $f::<RomuJr>,
+            $f::<RomuTrio>,
+            $f::<RomuQuad>,
+            $f::<RomuDuo>,
+            $f::<Sfc64>,
+            $f::<Splitmix64>,
+            $f::<Tylo64>,
+            $f::<Wyrand>,
+            $f::<Xorshift128plus>,
+            $f::<Xoshiro256plusplus>,
+            $f::<Xoshiro256starstar>,
+            $f::<Xoshiro256plus>,
+            $f::<Xoshiro512plusplus>,
+            $f::<Xoroshiro128plusplus>,
+            $f::<Xoroshiro128starstar>,
+            $f::<Xoroshiro128plus>,
+            $f::<Xoroshiro1024plusplus>,
+            $f::<Widen<Mwc128XXA32>>,
+            $f::<Widen<Pcg32>>,
+            $f::<Widen<RomuTrio32>>,
+            $f::<Widen<Sfc32>>,
+            $f::<Widen<Xoshiro128plusplus>>,
+        ]
+    };
+}
+
+pub(crate) use generators;
@@ -0,0 +1,73 @@
+//! Runs every generator from arbitrary states, including ones `seed_from()`
+//! never produces, and checks that the methods agree with the `u64()` stream.
+
+#![no_main]
+
+mod common;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use prng_benchmark::*;
+
+use common::{words, Raw};
+
+#[derive(Arbitrary, Debug)]
+struct Input<'a> {
+    generator: u8,
+    state: &'a [u8],
+    ops: Vec<Op>,
+}
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    U64,
+    U32,
+    F64,
+    Bounded(u64),
+    FillBytes(u16),
+}
+
+fn run<R: Raw>(input: &Input) {
+    let rng = R::from_raw(&mut words(input.state));
+    // Replays the stream, to check the results against plain `u64()` calls.
+    let twin = R::from_raw(&mut words(input.state));
+    let valid = rng.is_valid();
+
+    for op in &input.ops {
+        match *op {
+            Op::U64 => assert_eq!(rng.u64(), twin.u64()),
+            Op::U32 => assert_eq!(rng.u32(), twin.u32()),
+            Op::F64 => {
+                let x = rng.f64();
+                assert!((0.0..1.0).contains(&x), "{x} out of range");
+                assert_eq!(x, (twin.u64() >> 11) as f64 / (1u64 << 53) as f64);
+            }
+            // Rejection sampling never ends on a stuck generator.
+            Op::Bounded(_) if !valid => {}
+            Op::Bounded(bound) => {
+                let bound = bound.max(1);
+                let x = rng.bounded_u64(bound);
+                assert!(x < bound, "{x} out of range 0..{bound}");
+                assert_eq!(x, twin.bounded_u64(bound));
+            }
+            Op::FillBytes(len) => {
+                let mut bytes = vec![0; len as usize];
+                rng.fill_bytes(&mut bytes);
+
+                let mut chunks = bytes.chunks_exact(8);
+                for chunk in &mut chunks {
+                    assert_eq!(chunk, twin.u64().to_ne_bytes());
+                }
+                for &b in chunks.remainder() {
+                    assert_eq!(b, twin.u64() as u8);
+                }
+            }
+        }
+        assert!(!valid || rng.is_valid(), "invariant broken by {op:?}");
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let generators: &[fn(&Input)] = common::generators!(run);
+    generators[input.generator as usize % generators.len()](&input);
+});
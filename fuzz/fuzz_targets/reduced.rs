@@ -0,0 +1,89 @@
+//! Restores the reduced-width generators from arbitrary states, runs them and
+//! writes their raw output with arbitrary lengths.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use prng_benchmark::reduced::*;
+use prng_benchmark::{Mwc128XXA32, Mwc256XXA64, RomuJr, RomuTrio, RomuTrio32, Tylo64};
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    generator: u8,
+    state: u128,
+    seeds: Vec<u64>,
+    words: u16,
+}
+
+fn run<R: SmallState + Default>(input: &Input) {
+    let mask = u128::MAX >> (128 - R::STATE_BITS);
+    let rng = R::default();
+    rng.set_state(input.state);
+    assert_eq!(rng.state(), input.state & mask, "state doesn't round-trip");
+
+    let size = R::BITS as usize / 8;
+    let mut bytes = vec![0; input.words as usize * size];
+    rng.fill_raw(&mut bytes);
+
+    rng.set_state(input.state);
+    for chunk in bytes.chunks_exact(size) {
+        let word = rng.word();
+        assert!(R::BITS == 64 || word >> R::BITS == 0, "{word:#x} too wide");
+        assert_eq!(chunk, &word.to_le_bytes()[..size]);
+    }
+
+    let mut consumed = 0;
+    rng.seed_from(|| {
+        consumed += 1;
+        input.seeds.get(consumed - 1).copied().unwrap_or(0)
+    });
+    assert!(
+        consumed <= R::SEED_WORDS,
+        "consumed {consumed} words, more than SEED_WORDS = {}",
+        R::SEED_WORDS
+    );
+    let state = rng.state();
+    rng.set_state(state);
+    assert_eq!(rng.state(), state);
+}
+
+/// Checks the full width generators, which have more state than `SmallState`
+/// can hold, only through `ReducedRandom`.
+fn run_full<R: ReducedRandom + Default>(input: &Input) {
+    let rng = R::default();
+    rng.seed_from_u64(input.state as u64);
+    let size = R::BITS as usize / 8;
+    let mut bytes = vec![0; input.words as usize * size];
+    rng.fill_raw(&mut bytes);
+
+    rng.seed_from_u64(input.state as u64);
+    for chunk in bytes.chunks_exact(size) {
+        assert_eq!(chunk, &rng.word().to_le_bytes()[..size]);
+    }
+}
+
+type Run = fn(&Input);
+
+const GENERATORS: &[Run] = &[
+    run::<RomuJr8>,
+    run::<RomuJr16>,
+    run::<RomuJr32>,
+    run_full::<RomuJr>,
+    run::<RomuTrio8>,
+    run::<RomuTrio16>,
+    run_full::<RomuTrio32>,
+    run_full::<RomuTrio>,
+    run::<Tylo8>,
+    run::<Tylo16>,
+    run::<Tylo32>,
+    run_full::<Tylo64>,
+    run::<Mwc32XXA8>,
+    run::<Mwc64XXA16>,
+    run_full::<Mwc128XXA32>,
+    run_full::<Mwc256XXA64>,
+];
+
+fuzz_target!(|input: Input| {
+    GENERATORS[input.generator as usize % GENERATORS.len()](&input);
+});
@@ -0,0 +1,138 @@
+//! Seeds every generator from arbitrary words and builds generators with their
+//! constructors from arbitrary arguments, and checks that the results are
+//! valid states.
+
+#![no_main]
+
+mod common;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use prng_benchmark::*;
+
+use common::{words, Raw};
+
+#[derive(Arbitrary, Debug)]
+enum Input<'a> {
+    Seed {
+        generator: u8,
+        /// The state before seeding, which must not leak into the new one.
+        previous: &'a [u8],
+        seed: Seed,
+    },
+    Constructor(Constructor),
+}
+
+#[derive(Arbitrary, Debug)]
+enum Seed {
+    Words(Vec<u64>),
+    U64(u64),
+}
+
+#[derive(Arbitrary, Debug)]
+enum Constructor {
+    Sfc64(u64),
+    Jsf64(u64),
+    Mt19937_64(u64),
+    Pcg64Dxsm(u128, u128),
+    ChaCha8 {
+        key: [u8; 32],
+        nonce: u64,
+    },
+    ChaCha20Rfc8439 {
+        key: [u8; 32],
+        counter: u32,
+        nonce: [u8; 12],
+    },
+    Mwc256XXA64 {
+        x: [u64; 3],
+        c: u64,
+    },
+    Mwc128XXA32 {
+        x: [u32; 3],
+        c: u32,
+    },
+    Pcg32(u64, u64),
+    Sfc32(u64),
+}
+
+/// Runs `rng` for a while, checking that it stays valid.
+fn exercise<R: Raw>(rng: &R) {
+    assert!(rng.is_valid(), "invalid state");
+    for _ in 0..64 {
+        rng.u64();
+    }
+    assert!(rng.is_valid(), "state became invalid");
+}
+
+fn seed<R: Raw>(input: &Input) {
+    let Input::Seed { previous, seed, .. } = input else {
+        unreachable!()
+    };
+    let rng = R::from_raw(&mut words(&[]));
+    let twin = R::from_raw(&mut words(previous));
+
+    match seed {
+        Seed::Words(seeds) => {
+            let mut consumed = 0;
+            rng.seed_from(|| {
+                consumed += 1;
+                seeds.get(consumed - 1).copied().unwrap_or(0)
+            });
+            assert!(
+                consumed <= R::SEED_WORDS,
+                "consumed {consumed} words, more than SEED_WORDS = {}",
+                R::SEED_WORDS
+            );
+            let mut replay = seeds.iter().copied().chain(std::iter::repeat(0));
+            twin.seed_from(|| replay.next().unwrap());
+        }
+        &Seed::U64(seed) => {
+            rng.seed_from_u64(seed);
+            twin.seed_from_u64(seed);
+        }
+    }
+
+    assert!(rng.is_valid(), "seeding produced an invalid state");
+    for _ in 0..64 {
+        assert_eq!(rng.u64(), twin.u64(), "the previous state leaked through");
+    }
+    assert!(rng.is_valid(), "state became invalid");
+}
+
+fn construct(constructor: &Constructor) {
+    match *constructor {
+        Constructor::Sfc64(seed) => exercise(&Sfc64::new(seed)),
+        Constructor::Jsf64(seed) => exercise(&Jsf64::new(seed)),
+        Constructor::Mt19937_64(seed) => exercise(&Mt19937_64::new(seed)),
+        Constructor::Pcg64Dxsm(initstate, initseq) => exercise(&Pcg64Dxsm::new(initstate, initseq)),
+        Constructor::ChaCha8 { key, nonce } => exercise(&ChaCha8::new(key, nonce)),
+        Constructor::ChaCha20Rfc8439 {
+            key,
+            counter,
+            nonce,
+        } => exercise(&ChaCha20::from_rfc8439(key, counter, nonce)),
+        Constructor::Mwc256XXA64 { x, c } => {
+            if let Some(mwc) = Mwc::from_state(x, c) {
+                exercise(&Mwc256XXA64 { mwc });
+            }
+        }
+        Constructor::Mwc128XXA32 { x, c } => {
+            if let Some(mwc) = Mwc32::from_state(x, c) {
+                exercise(&Widen(Mwc128XXA32 { mwc }));
+            }
+        }
+        Constructor::Pcg32(initstate, initseq) => exercise(&Widen(Pcg32::new(initstate, initseq))),
+        Constructor::Sfc32(seed) => exercise(&Widen(Sfc32::new(seed))),
+    }
+}
+
+fuzz_target!(|input: Input| {
+    match &input {
+        Input::Seed { generator, .. } => {
+            let generators: &[fn(&Input)] = common::generators!(seed);
+            generators[*generator as usize % generators.len()](&input);
+        }
+        Input::Constructor(constructor) => construct(constructor),
+    }
+});
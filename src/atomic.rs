@@ -0,0 +1,221 @@
+//! Generators that can be shared between threads, like a process-global
+//! generator in a `static`.
+//!
+//! The other generators keep their state in `Cell`s, which makes them `!Sync`.
+//! Counter-based generators only add a constant to their state, so
+//! `AtomicWyrand` and `AtomicSplitmix64` advance it with a single `fetch_add`
+//! and never wait. `Shared` makes any other generator `Sync` with a spin lock
+//! it takes with a compare-and-swap around every call.
+//!
+//! Either way all threads update the same cache line, so under contention they
+//! are much slower than a generator per thread, like `wyrand_u64()`. The
+//! `contention` benchmark compares them.
+//!
+//! ```
+//! use prng_benchmark::atomic::{AtomicWyrand, Shared};
+//! use prng_benchmark::{Random, Xoshiro256plusplus};
+//!
+//! static IDS: AtomicWyrand = AtomicWyrand::from_seed(42);
+//! static DICE: Shared<Xoshiro256plusplus> = Shared::new(Xoshiro256plusplus::from_seed(42));
+//!
+//! std::thread::scope(|s| {
+//!     for _ in 0..4 {
+//!         s.spawn(|| (IDS.u64(), DICE.bounded_u64(6) + 1));
+//!     }
+//! });
+//! ```
+
+use std::cell::UnsafeCell;
+use std::hint::spin_loop;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use crate::{
+    const_seeds, os_seeds, splitmix64_mix, wyrand_mix, Random, SeedableRandom, Splitmix64, Wyrand,
+    SPLITMIX64_GAMMA, WYRAND_INCREMENT,
+};
+
+/// `Wyrand` with an atomic state. Produces the same stream as `Wyrand` when used
+/// from a single thread, and hands out every output exactly once otherwise.
+pub struct AtomicWyrand {
+    pub state: AtomicU64,
+}
+
+impl AtomicWyrand {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 1]) -> Self {
+        Self {
+            state: AtomicU64::new(Wyrand::from_words(words).state.get()),
+        }
+    }
+}
+
+impl Default for AtomicWyrand {
+    fn default() -> Self {
+        let s = Self {
+            state: AtomicU64::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for AtomicWyrand {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        wyrand_mix(self.state.fetch_add(WYRAND_INCREMENT, Ordering::Relaxed))
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for AtomicWyrand {
+    const SEED_WORDS: usize = 1;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { state } = Self::from_words(std::array::from_fn(|_| next()));
+        self.state.store(state.into_inner(), Ordering::Relaxed);
+    }
+}
+
+/// `Splitmix64` with an atomic state. Produces the same stream as `Splitmix64`
+/// when used from a single thread, and hands out every output exactly once
+/// otherwise.
+pub struct AtomicSplitmix64 {
+    pub state: AtomicU64,
+}
+
+impl AtomicSplitmix64 {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 1]) -> Self {
+        Self {
+            state: AtomicU64::new(Splitmix64::from_words(words).state.get()),
+        }
+    }
+}
+
+impl Default for AtomicSplitmix64 {
+    fn default() -> Self {
+        let s = Self {
+            state: AtomicU64::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for AtomicSplitmix64 {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        splitmix64_mix(self.state.fetch_add(SPLITMIX64_GAMMA, Ordering::Relaxed))
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for AtomicSplitmix64 {
+    const SEED_WORDS: usize = 1;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { state } = Self::from_words(std::array::from_fn(|_| next()));
+        self.state.store(state.into_inner(), Ordering::Relaxed);
+    }
+}
+
+/// Shares any generator between threads by taking a spin lock around every
+/// call. The lock is a flag set with a compare-and-swap, which is cheaper than
+/// a `Mutex` as long as the calls are short, as they are for generators.
+#[derive(Default)]
+pub struct Shared<R> {
+    locked: AtomicBool,
+    rng: UnsafeCell<R>,
+}
+
+// SAFETY: Only the thread holding the lock accesses `rng`.
+unsafe impl<R: Send> Sync for Shared<R> {}
+
+/// Releases the lock, also when the generator panics.
+struct Unlock<'a>(&'a AtomicBool);
+
+impl Drop for Unlock<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+impl<R> Shared<R> {
+    pub const fn new(rng: R) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            rng: UnsafeCell::new(rng),
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.rng.into_inner()
+    }
+
+    /// Calls `f` with exclusive access to the generator, so that several calls
+    /// only take the lock once. Calling into the same `Shared` from `f`
+    /// deadlocks.
+    #[inline(always)]
+    pub fn with<T>(&self, f: impl FnOnce(&R) -> T) -> T {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            // Waits with plain loads, which don't take the cache line away from
+            // the thread holding the lock.
+            while self.locked.load(Ordering::Relaxed) {
+                spin_loop();
+            }
+        }
+        let _unlock = Unlock(&self.locked);
+
+        // SAFETY: The lock is held until `_unlock` is dropped, and `f` can't
+        // keep the reference, as `T` can't borrow from it.
+        f(unsafe { &*self.rng.get() })
+    }
+}
+
+impl<R: Random> Random for Shared<R> {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        self.with(R::u64)
+    }
+
+    fn seed(&self) {
+        self.with(R::seed)
+    }
+
+    #[inline(always)]
+    fn u32(&self) -> u32 {
+        self.with(R::u32)
+    }
+
+    fn fill_bytes(&self, slice: &mut [u8]) {
+        self.with(|rng| rng.fill_bytes(slice))
+    }
+}
+
+impl<R: SeedableRandom> SeedableRandom for Shared<R> {
+    const SEED_WORDS: usize = R::SEED_WORDS;
+
+    fn seed_from(&self, next: impl FnMut() -> u64) {
+        self.with(|rng| rng.seed_from(next))
+    }
+}
@@ -0,0 +1,134 @@
+//! Measures the cycles that chaotic generators end up on, using scaled-down variants whose state
+//! is small enough to walk a whole cycle.
+//!
+//! ```sh
+//! cargo run --release --bin prng-cycles -- --seeds 1000 romu_jr8 romu_trio8 tylo8
+//! ```
+//!
+//! Usage: `prng-cycles [--seeds <n>] [--seed <u64>] [--max-steps <n>] [--short <n>] [generator...]`
+//!
+//! For every generator, runs Brent's cycle detection from `--seeds` seeds (100 by default) and
+//! prints the distribution of cycle lengths by power of two. Cycles shorter than `--short` steps
+//! (2^16 by default) are counted as short. Seeds whose cycle or tail exceeds `--max-steps` (2^32
+//! by default) are counted as over the limit. Seeds come from the operating system, unless
+//! `--seed` makes the run reproducible. Without generators, all are measured.
+
+use std::error::Error;
+
+use prng_benchmark::reduced::*;
+
+/// Measures a generator and prints its report.
+type Measure = fn(&Options);
+
+const GENERATORS: &[(&str, Measure)] = &[
+    ("romu_jr8", measure::<RomuJr8>),
+    ("romu_jr16", measure::<RomuJr16>),
+    ("romu_trio8", measure::<RomuTrio8>),
+    ("romu_trio16", measure::<RomuTrio16>),
+    ("tylo8", measure::<Tylo8>),
+    ("tylo16", measure::<Tylo16>),
+    ("mwc32xxa8", measure::<Mwc32XXA8>),
+];
+
+struct Options {
+    seeds: u64,
+    seed: Option<u64>,
+    max_steps: u64,
+    short: u64,
+}
+
+fn measure<R: SmallState + Default>(options: &Options) {
+    let mut lengths = Vec::new();
+    let mut tails = Vec::new();
+    let mut over_limit = 0;
+
+    for i in 0..options.seeds {
+        let rng = R::default();
+        if let Some(seed) = options.seed {
+            rng.seed_from_u64(seed.wrapping_add(i));
+        }
+        match find_cycle(&rng, options.max_steps) {
+            Some(cycle) => {
+                lengths.push(cycle.length);
+                tails.push(cycle.tail);
+            }
+            None => over_limit += 1,
+        }
+    }
+    lengths.sort_unstable();
+
+    let name = std::any::type_name::<R>().rsplit("::").next().unwrap();
+    println!("## {name}\n");
+    println!("State bits: {}", R::STATE_BITS);
+    let short = lengths.iter().filter(|&&l| l < options.short).count();
+    println!(
+        "Short cycles (< {} steps): {short} of {} seeds, p = {:.4}",
+        options.short,
+        options.seeds,
+        short as f64 / options.seeds as f64
+    );
+    println!(
+        "Over the limit of {} steps: {over_limit}",
+        options.max_steps
+    );
+    if let (Some(min), Some(max)) = (lengths.first(), lengths.last()) {
+        let median = lengths[lengths.len() / 2];
+        println!(
+            "Cycle length: min {min}, median {median}, max {max}, mean log2 {:.2}",
+            lengths.iter().map(|&l| (l as f64).log2()).sum::<f64>() / lengths.len() as f64
+        );
+        println!(
+            "Longest tail: {}\n",
+            tails.iter().max().copied().unwrap_or(0)
+        );
+
+        println!("| cycle length | seeds | share |");
+        println!("|---|---:|---:|");
+        let mut buckets = [0u64; 65];
+        for &l in &lengths {
+            buckets[l.ilog2() as usize] += 1;
+        }
+        for (log2, &count) in buckets.iter().enumerate().filter(|(_, &c)| c != 0) {
+            println!(
+                "| 2^{log2}..2^{} | {count} | {:.4} |",
+                log2 + 1,
+                count as f64 / options.seeds as f64
+            );
+        }
+    }
+    println!();
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut options = Options {
+        seeds: 100,
+        seed: None,
+        max_steps: 1 << 32,
+        short: 1 << 16,
+    };
+    let mut names = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+        match arg.as_str() {
+            "--seeds" => options.seeds = value()?.parse()?,
+            "--seed" => options.seed = Some(value()?.parse()?),
+            "--max-steps" => options.max_steps = value()?.parse()?,
+            "--short" => options.short = value()?.parse()?,
+            _ => names.push(arg),
+        }
+    }
+
+    if names.is_empty() {
+        names = GENERATORS.iter().map(|(n, _)| n.to_string()).collect();
+    }
+    for name in names {
+        let (_, measure) = GENERATORS
+            .iter()
+            .find(|(n, _)| *n == name)
+            .ok_or_else(|| format!("unknown generator {name}"))?;
+        measure(&options);
+    }
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! Writes the raw output of a generator to stdout, for piping into PractRand.
+//!
+//! ```sh
+//! cargo run --release --bin prng-raw -- tylo16 | RNG_test stdin16
+//! ```
+//!
+//! Usage: `prng-raw [--seed <u64>] <generator>` or `prng-raw --list`
+//!
+//! Without `--seed`, the generator is seeded from the operating system. Each word is written in
+//! little-endian order with the width of the generator, which is what the matching `stdin8`,
+//! `stdin16`, `stdin32` or `stdin64` option of `RNG_test` expects.
+
+use std::error::Error;
+use std::io::{self, ErrorKind, Write};
+
+use prng_benchmark::reduced::*;
+use prng_benchmark::{Mwc128XXA32, Mwc256XXA64, RomuJr, RomuTrio, RomuTrio32, Tylo64};
+
+/// Streams a generator, optionally seeded deterministically.
+type Stream = fn(Option<u64>) -> io::Result<()>;
+
+/// The generators by name, grouped by family from the smallest to the full width.
+const GENERATORS: &[(&str, u32, Stream)] = &[
+    ("romu_jr8", RomuJr8::BITS, stream::<RomuJr8>),
+    ("romu_jr16", RomuJr16::BITS, stream::<RomuJr16>),
+    ("romu_jr32", RomuJr32::BITS, stream::<RomuJr32>),
+    ("romu_jr", RomuJr::BITS, stream::<RomuJr>),
+    ("romu_trio8", RomuTrio8::BITS, stream::<RomuTrio8>),
+    ("romu_trio16", RomuTrio16::BITS, stream::<RomuTrio16>),
+    ("romu_trio32", RomuTrio32::BITS, stream::<RomuTrio32>),
+    ("romu_trio", RomuTrio::BITS, stream::<RomuTrio>),
+    ("tylo8", Tylo8::BITS, stream::<Tylo8>),
+    ("tylo16", Tylo16::BITS, stream::<Tylo16>),
+    ("tylo32", Tylo32::BITS, stream::<Tylo32>),
+    ("tylo64", Tylo64::BITS, stream::<Tylo64>),
+    ("mwc32xxa8", Mwc32XXA8::BITS, stream::<Mwc32XXA8>),
+    ("mwc64xxa16", Mwc64XXA16::BITS, stream::<Mwc64XXA16>),
+    ("mwc128xxa32", Mwc128XXA32::BITS, stream::<Mwc128XXA32>),
+    ("mwc256xxa64", Mwc256XXA64::BITS, stream::<Mwc256XXA64>),
+];
+
+/// Writes until stdout is closed, which is how PractRand ends a run.
+fn stream<R: ReducedRandom + Default>(seed: Option<u64>) -> io::Result<()> {
+    let rng = R::default();
+    if let Some(seed) = seed {
+        rng.seed_from_u64(seed);
+    }
+
+    let mut stdout = io::stdout().lock();
+    let mut buffer = vec![0u8; 1 << 16];
+    loop {
+        rng.fill_raw(&mut buffer);
+        match stdout.write_all(&buffer) {
+            Err(e) if e.kind() == ErrorKind::BrokenPipe => return Ok(()),
+            result => result?,
+        }
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut seed = None;
+    let mut name = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--list" => {
+                for (name, bits, _) in GENERATORS {
+                    println!("{name}\tstdin{bits}");
+                }
+                return Ok(());
+            }
+            "--seed" => seed = Some(args.next().ok_or("--seed needs a value")?.parse()?),
+            _ => name = Some(arg),
+        }
+    }
+
+    let name = name.ok_or("usage: prng-raw [--seed <u64>] <generator> | --list")?;
+    let (_, _, stream) = GENERATORS
+        .iter()
+        .find(|(n, _, _)| *n == name)
+        .ok_or_else(|| format!("unknown generator {name}, see --list"))?;
+    Ok(stream(seed)?)
+}
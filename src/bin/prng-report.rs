@@ -0,0 +1,326 @@
+//! Builds a comparison table from the estimates criterion writes into `target/criterion`.
+//!
+//! ```sh
+//! cargo bench
+//! cargo run --release --features report --bin prng-report -- --metadata
+//! ```
+//!
+//! Usage: `prng-report [--csv] [--metadata] [--dir <criterion dir>] [group...]`
+//!
+//! Without groups, the `scalar`, `bytes` and `thread_local` groups are reported. Benchmarks
+//! without a throughput are counted as producing a single element per iteration. Elements are
+//! `u64`s, unless `ELEMENTS` lists another width for the group.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::mem::size_of;
+use std::path::{Path, PathBuf};
+
+use prng_benchmark::*;
+use serde_json::Value;
+
+const DEFAULT_GROUPS: [&str; 3] = ["scalar", "bytes", "thread_local"];
+
+/// What one element of a group is, for the groups that don't produce `u64`s.
+const ELEMENTS: &[(&str, Element)] = &[(
+    "scalar_u32",
+    Element {
+        name: "u32",
+        bytes: 4.0,
+    },
+)];
+
+struct Element {
+    name: &'static str,
+    bytes: f64,
+}
+
+fn element(group: &str) -> &'static Element {
+    const U64: Element = Element {
+        name: "u64",
+        bytes: 8.0,
+    };
+    ELEMENTS
+        .iter()
+        .find(|(name, _)| *name == group)
+        .map_or(&U64, |(_, element)| element)
+}
+
+struct Metadata {
+    id: &'static str,
+    state_bytes: usize,
+    period: &'static str,
+}
+
+const fn metadata<T>(id: &'static str, period: &'static str) -> Metadata {
+    Metadata {
+        id,
+        state_bytes: size_of::<T>(),
+        period,
+    }
+}
+
+/// Keyed by the benchmark id. Generators without a fixed period (the chaotic ones) are listed as
+/// "not fixed". The periods of the 32-bit generators count `u32` outputs, two of which make up
+/// a `u64`.
+const METADATA: &[Metadata] = &[
+    metadata::<ChaCha8>("chacha8", "2^67"),
+    metadata::<ChaCha12>("chacha12", "2^67"),
+    metadata::<ChaCha20>("chacha20", "2^67"),
+    metadata::<Jsf64>("jsf64", "not fixed"),
+    metadata::<Lehmer64>("lehmer64", "2^126"),
+    metadata::<Mt19937_64>("mt19937_64", "2^19937 - 1"),
+    metadata::<Mwc128>("mwc128", "~2^127"),
+    metadata::<Mwc192>("mwc192", "~2^191"),
+    metadata::<Mwc256>("mwc256", "~2^255"),
+    metadata::<Mwc128XXA32>("mwc128xxa32", "~2^127 u32s"),
+    metadata::<Mwc256XXA64>("mwc256xxa64", "~2^255"),
+    metadata::<Pcg32>("pcg32", "2^64 u32s"),
+    metadata::<Pcg64>("pcg64", "2^128"),
+    metadata::<Pcg64Fast>("pcg64fast", "2^126"),
+    metadata::<Pcg64Dxsm>("pcg64dxsm", "2^128"),
+    metadata::<Pcg64DxsmFast>("pcg64dxsmfast", "2^126"),
+    metadata::<RomuDuo>("romu_duo", "not fixed"),
+    metadata::<RomuJr>("romu_jr", "not fixed"),
+    metadata::<RomuQuad>("romu_quad", "not fixed"),
+    metadata::<RomuTrio>("romu_trio", "not fixed"),
+    metadata::<RomuTrio32>("romu_trio32", "not fixed"),
+    metadata::<Sfc32>("sfc32", ">= 2^32 u32s"),
+    metadata::<Sfc64>("sfc64", ">= 2^64"),
+    metadata::<Splitmix64>("splitmix64", "2^64"),
+    metadata::<Tylo64>("tylo64", ">= 2^64"),
+    metadata::<Wyrand>("wyrand", "2^64"),
+    metadata::<Xoroshiro128plus>("xoroshiro128+", "2^128 - 1"),
+    metadata::<Xoroshiro128plusplus>("xoroshiro128++", "2^128 - 1"),
+    metadata::<Xoroshiro128starstar>("xoroshiro128**", "2^128 - 1"),
+    metadata::<Xoroshiro1024plusplus>("xoroshiro1024++", "2^1024 - 1"),
+    metadata::<Xorshift128plus>("xorshift128+", "2^128 - 1"),
+    metadata::<Xoshiro128plusplus>("xoshiro128++", "2^128 - 1 u32s"),
+    metadata::<Xoshiro256plus>("xoshiro256+", "2^256 - 1"),
+    metadata::<Xoshiro256plusplus>("xoshiro256++", "2^256 - 1"),
+    metadata::<Xoshiro256starstar>("xoshiro256**", "2^256 - 1"),
+    metadata::<Xoshiro512plusplus>("xoshiro512++", "2^512 - 1"),
+];
+
+struct Options {
+    csv: bool,
+    metadata: bool,
+    dir: PathBuf,
+    groups: Vec<String>,
+}
+
+impl Options {
+    fn parse() -> Result<Self, Box<dyn Error>> {
+        let mut options = Self {
+            csv: false,
+            metadata: false,
+            dir: PathBuf::from("target/criterion"),
+            groups: Vec::new(),
+        };
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--csv" => options.csv = true,
+                "--metadata" => options.metadata = true,
+                "--dir" => options.dir = args.next().ok_or("--dir needs a value")?.into(),
+                _ if arg.starts_with("--") => return Err(format!("unknown option {arg}").into()),
+                _ => options.groups.push(arg),
+            }
+        }
+
+        if options.groups.is_empty() {
+            options.groups = DEFAULT_GROUPS.iter().map(|x| x.to_string()).collect();
+        }
+
+        Ok(options)
+    }
+}
+
+struct Row {
+    function: String,
+    value: String,
+    /// Mean time per element in ns, with the lower and upper bound of its confidence interval.
+    ns: f64,
+    ns_lower: f64,
+    ns_upper: f64,
+    confidence_level: f64,
+    element: &'static Element,
+}
+
+impl Row {
+    fn gb_per_s(&self) -> f64 {
+        self.element.bytes / self.ns
+    }
+}
+
+fn read_json(path: &Path) -> Result<Value, Box<dyn Error>> {
+    let text = fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+    Ok(serde_json::from_str(&text)?)
+}
+
+/// Returns how many elements one iteration of the benchmark produces.
+fn elements_per_iteration(benchmark: &Value, element: &Element) -> f64 {
+    let throughput = &benchmark["throughput"];
+    match throughput["Bytes"].as_f64() {
+        Some(bytes) => bytes / element.bytes,
+        None => throughput["Elements"].as_f64().unwrap_or(1.0),
+    }
+}
+
+fn read_row(dir: &Path, element: &'static Element) -> Result<Row, Box<dyn Error>> {
+    let benchmark = read_json(&dir.join("benchmark.json"))?;
+    let estimates = read_json(&dir.join("estimates.json"))?;
+
+    let mean = &estimates["mean"];
+    let interval = &mean["confidence_interval"];
+    let number = |value: &Value| value.as_f64().ok_or("malformed estimates.json");
+    let scale = elements_per_iteration(&benchmark, element);
+
+    Ok(Row {
+        function: benchmark["function_id"]
+            .as_str()
+            .ok_or("malformed benchmark.json")?
+            .to_string(),
+        value: benchmark["value_str"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        ns: number(&mean["point_estimate"])? / scale,
+        ns_lower: number(&interval["lower_bound"])? / scale,
+        ns_upper: number(&interval["upper_bound"])? / scale,
+        confidence_level: number(&interval["confidence_level"])?,
+        element,
+    })
+}
+
+/// Collects every benchmark of a group, which criterion stores as `<group>/<id>/new`.
+fn read_group(dir: &Path, group: &str) -> Result<Vec<Row>, Box<dyn Error>> {
+    let group_dir = dir.join(group);
+    let mut rows = Vec::new();
+    let mut pending = vec![group_dir.clone()];
+
+    while let Some(dir) = pending.pop() {
+        let new = dir.join("new");
+        if new.join("estimates.json").is_file() {
+            rows.push(read_row(&new, element(group))?);
+            continue;
+        }
+        for entry in fs::read_dir(&dir).map_err(|err| format!("{}: {err}", dir.display()))? {
+            let path = entry?.path();
+            if path.is_dir() && !path.ends_with("report") {
+                pending.push(path);
+            }
+        }
+    }
+
+    if rows.is_empty() {
+        return Err(format!("no estimates found in {}", group_dir.display()).into());
+    }
+
+    rows.sort_by(|a, b| a.ns.total_cmp(&b.ns));
+    Ok(rows)
+}
+
+/// Uses the function id as label and only appends the parameter if a function
+/// was benchmarked with several of them.
+fn labels(rows: &[Row]) -> Vec<String> {
+    let mut count = BTreeMap::new();
+    rows.iter()
+        .for_each(|row| *count.entry(row.function.as_str()).or_insert(0) += 1);
+
+    rows.iter()
+        .map(|row| match count[row.function.as_str()] {
+            1 => row.function.clone(),
+            _ => format!("{}/{}", row.function, row.value),
+        })
+        .collect()
+}
+
+fn lookup(function: &str) -> Option<&'static Metadata> {
+    METADATA.iter().find(|metadata| metadata.id == function)
+}
+
+fn print_markdown(group: &str, rows: &[Row], with_metadata: bool) {
+    let fastest = rows[0].ns;
+    let confidence = rows[0].confidence_level * 100.0;
+
+    println!("## {group}\n");
+    let unit = rows[0].element.name;
+    print!("| Generator | ns/{unit} | {confidence:.0}% CI | GB/s | Relative |");
+    if with_metadata {
+        print!(" State (bytes) | Period |");
+    }
+    println!();
+    print!("|---|---:|---:|---:|---:|");
+    if with_metadata {
+        print!("---:|---:|");
+    }
+    println!();
+
+    for (row, label) in rows.iter().zip(labels(rows)) {
+        print!(
+            "| {label} | {:.3} | {:.3} - {:.3} | {:.2} | {:.2}x |",
+            row.ns,
+            row.ns_lower,
+            row.ns_upper,
+            row.gb_per_s(),
+            row.ns / fastest,
+        );
+        if with_metadata {
+            match lookup(&row.function) {
+                Some(metadata) => print!(" {} | {} |", metadata.state_bytes, metadata.period),
+                None => print!(" | |"),
+            }
+        }
+        println!();
+    }
+    println!();
+}
+
+fn print_csv(group: &str, rows: &[Row], with_metadata: bool) {
+    let fastest = rows[0].ns;
+
+    for (row, label) in rows.iter().zip(labels(rows)) {
+        print!(
+            "{group},{label},{},{},{},{},{},{}",
+            row.element.name,
+            row.ns,
+            row.ns_lower,
+            row.ns_upper,
+            row.gb_per_s(),
+            row.ns / fastest,
+        );
+        if with_metadata {
+            match lookup(&row.function) {
+                Some(metadata) => print!(",{},{}", metadata.state_bytes, metadata.period),
+                None => print!(",,"),
+            }
+        }
+        println!();
+    }
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let options = Options::parse()?;
+
+    if options.csv {
+        print!("group,generator,element,ns_per_element,ci_lower,ci_upper,gb_per_s,relative");
+        if options.metadata {
+            print!(",state_bytes,period");
+        }
+        println!();
+    }
+
+    for group in &options.groups {
+        let rows = read_group(&options.dir, group)?;
+        if options.csv {
+            print_csv(group, &rows, options.metadata);
+        } else {
+            print_markdown(group, &rows, options.metadata);
+        }
+    }
+
+    Ok(())
+}
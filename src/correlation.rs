@@ -0,0 +1,132 @@
+//! Looks for correlations between generators built from related seeds.
+//!
+//! Generators whose seed becomes the state more or less directly, like a
+//! counter, can produce similar streams for seeds that differ only a little.
+//! `interleave()` takes one output from every generator per round, and
+//! `analyze()` compares each output with the next one of the interleaved
+//! stream, which comes from the generator with the neighbouring seed:
+//!
+//! - The bit agreement of two independent streams is 50%. A z-score per pair
+//!   of neighbouring generators shows streams that agree too often or too
+//!   rarely.
+//! - The XOR of two independent outputs is uniform. A chi-square test on its
+//!   bytes shows any structure the differences share.
+//!
+//! ```
+//! use prng_benchmark::correlation::{self, Relation};
+//! use prng_benchmark::{SeedableRandom, Wyrand};
+//!
+//! let streams: Vec<Wyrand> = correlation::related_seeds(42, Relation::Sequential, 64)
+//!     .map(|seed| {
+//!         let rng = Wyrand::default();
+//!         rng.seed_from_u64(seed);
+//!         rng
+//!     })
+//!     .collect();
+//! let report = correlation::analyze(&correlation::interleave(&streams, 256), streams.len());
+//! assert!(!report.is_suspicious());
+//! ```
+
+use crate::Random;
+
+/// How the seeds of the generators relate to each other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Relation {
+    /// `base`, `base + 1`, `base + 2`, ...
+    Sequential,
+    /// `base` with bit 0, 1, 2, ... flipped, wrapping around after 64 seeds.
+    BitFlip,
+}
+
+/// Returns `count` seeds related to `base`.
+pub fn related_seeds(base: u64, relation: Relation, count: usize) -> impl Iterator<Item = u64> {
+    (0..count as u64).map(move |i| match relation {
+        Relation::Sequential => base.wrapping_add(i),
+        Relation::BitFlip => base ^ (1 << (i % 64)),
+    })
+}
+
+/// Returns `rounds` outputs of each generator, one output per generator and
+/// round, in the order of `streams`.
+pub fn interleave<R: Random>(streams: &[R], rounds: usize) -> Vec<u64> {
+    let mut words = Vec::with_capacity(streams.len() * rounds);
+    for _ in 0..rounds {
+        words.extend(streams.iter().map(Random::u64));
+    }
+    words
+}
+
+/// The |z| above which `is_suspicious()` flags a result. Corresponds to a
+/// two-sided p-value of 5.7e-7, so that hundreds of pairs and repeated runs
+/// don't raise false alarms.
+pub const SUSPICIOUS_Z: f64 = 5.0;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    /// The z-score of the bit agreement of the pair of neighbouring streams
+    /// that deviates the most from 50%.
+    pub worst_agreement_z: f64,
+    /// The index of the first stream of that pair.
+    pub worst_pair: usize,
+    /// The chi-square statistic of the bytes of the XOR of neighbouring streams.
+    pub xor_chi_square: f64,
+    /// The degrees of freedom of `xor_chi_square`.
+    pub degrees_of_freedom: usize,
+}
+
+impl Report {
+    /// Returns the z-score equivalent of `xor_chi_square`, using the
+    /// Wilson-Hilferty transformation.
+    pub fn xor_z(&self) -> f64 {
+        let k = self.degrees_of_freedom as f64;
+        let h = 2.0 / (9.0 * k);
+        ((self.xor_chi_square / k).cbrt() - (1.0 - h)) / h.sqrt()
+    }
+
+    pub fn is_suspicious(&self) -> bool {
+        self.worst_agreement_z.abs() > SUSPICIOUS_Z || self.xor_z() > SUSPICIOUS_Z
+    }
+}
+
+/// Analyzes a stream interleaved from `streams` generators by `interleave()`.
+pub fn analyze(words: &[u64], streams: usize) -> Report {
+    assert!(streams >= 2, "needs at least two streams");
+    assert!(
+        words.len().is_multiple_of(streams),
+        "length is not a multiple of the number of streams"
+    );
+
+    let mut agreement = vec![0u64; streams - 1];
+    let mut histogram = [0u64; 256];
+    for round in words.chunks_exact(streams) {
+        for (pair, w) in round.windows(2).enumerate() {
+            let xor = w[0] ^ w[1];
+            agreement[pair] += (64 - xor.count_ones()) as u64;
+            xor.to_le_bytes()
+                .iter()
+                .for_each(|&b| histogram[b as usize] += 1);
+        }
+    }
+
+    // Every pair compares 64 bits per round, each agreeing with p = 1/2.
+    let bits = 64.0 * (words.len() / streams) as f64;
+    let (worst_pair, worst_agreement_z) = agreement
+        .iter()
+        .map(|&a| (a as f64 - bits / 2.0) / (bits / 4.0).sqrt())
+        .enumerate()
+        .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+        .unwrap();
+
+    let expected = histogram.iter().sum::<u64>() as f64 / 256.0;
+    let xor_chi_square = histogram
+        .iter()
+        .map(|&o| (o as f64 - expected).powi(2) / expected)
+        .sum();
+
+    Report {
+        worst_agreement_z,
+        worst_pair,
+        xor_chi_square,
+        degrees_of_freedom: 255,
+    }
+}
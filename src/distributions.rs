@@ -0,0 +1,80 @@
+//! Non-uniform distributions that work with every `Random` implementor.
+//!
+//! ```
+//! use prng_benchmark::distributions::{Distribution, Normal};
+//! use prng_benchmark::Xoshiro256plusplus;
+//!
+//! let rng = Xoshiro256plusplus::default();
+//! let normal = Normal::new(10.0, 2.0).unwrap();
+//! let x = normal.sample(&rng);
+//! # assert!(x.is_finite());
+//! ```
+
+use crate::Random;
+
+mod bernoulli;
+mod binomial;
+mod cauchy;
+mod exponential;
+mod gamma;
+mod hypergeometric;
+mod normal;
+mod poisson;
+mod weighted;
+mod ziggurat;
+
+pub use bernoulli::{Bernoulli, Geometric};
+pub use binomial::Binomial;
+pub use cauchy::Cauchy;
+pub use exponential::{Exp, Exp1};
+pub use gamma::{Beta, Gamma};
+pub use hypergeometric::Hypergeometric;
+pub use normal::{LogNormal, Normal, StandardNormal};
+pub use poisson::Poisson;
+pub use weighted::{AliasTable, CumulativeWeights};
+
+pub trait Distribution<T> {
+    fn sample<R: Random>(&self, rng: &R) -> T;
+}
+
+/// Returns a uniform `f64` in the open interval `(0, 1)`.
+#[inline(always)]
+fn open01<R: Random>(rng: &R) -> f64 {
+    ((rng.u64() >> 11) as f64 + 0.5) * (1.0 / (1u64 << 53) as f64)
+}
+
+/// Returns `ln(Gamma(x))` for `x > 0`, using Stirling's series. Shifts small
+/// arguments up to 7 first, which keeps the error in the order of 1e-15.
+fn ln_gamma(x: f64) -> f64 {
+    const A: [f64; 10] = [
+        8.333333333333333e-02,
+        -2.777777777777778e-03,
+        7.936507936507937e-04,
+        -5.952380952380952e-04,
+        8.417508417508418e-04,
+        -1.917526917526918e-03,
+        6.41025641025641e-03,
+        -2.955065359477124e-02,
+        1.796443723688307e-01,
+        -1.39243221690590e+00,
+    ];
+    const LN_2PI: f64 = 1.8378770664093453;
+
+    if x == 1.0 || x == 2.0 {
+        return 0.0;
+    }
+
+    let shift = if x < 7.0 { (7.0 - x).floor() } else { 0.0 };
+    let x0 = x + shift;
+    let x2 = 1.0 / (x0 * x0);
+    let series = A.iter().rev().fold(0.0, |sum, a| sum * x2 + a);
+    let mut result = series / x0 + 0.5 * LN_2PI + (x0 - 0.5) * x0.ln() - x0;
+
+    // ln(Gamma(x)) = ln(Gamma(x + n)) - ln(x (x + 1) ... (x + n - 1))
+    let mut y = x0;
+    for _ in 0..shift as u32 {
+        y -= 1.0;
+        result -= y.ln();
+    }
+    result
+}
@@ -0,0 +1,83 @@
+use super::{open01, Distribution};
+use crate::Random;
+
+/// A Bernoulli trial that compares a raw `u64()` against a fixed-point
+/// threshold, so no float conversion happens while sampling.
+#[derive(Clone, Copy, Debug)]
+pub struct Bernoulli {
+    /// `p * 2^64`, or `u64::MAX` for `p == 1`.
+    threshold: u64,
+}
+
+impl Bernoulli {
+    const ALWAYS: u64 = u64::MAX;
+
+    /// Returns `None` if `p` isn't in `[0, 1]`.
+    ///
+    /// Every `f64` in `[0, 1)` is a multiple of 2^-64 or gets rounded down to
+    /// one, so the probability is exact up to that precision.
+    pub fn new(p: f64) -> Option<Self> {
+        if !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+
+        let threshold = if p == 1.0 {
+            Self::ALWAYS
+        } else {
+            (p * 18446744073709551616.0) as u64
+        };
+        Some(Self { threshold })
+    }
+
+    /// Returns `None` if `denominator` is 0 or smaller than `numerator`.
+    pub fn from_ratio(numerator: u64, denominator: u64) -> Option<Self> {
+        if denominator == 0 || numerator > denominator {
+            return None;
+        }
+
+        let threshold = if numerator == denominator {
+            Self::ALWAYS
+        } else {
+            (((numerator as u128) << 64) / denominator as u128) as u64
+        };
+        Some(Self { threshold })
+    }
+}
+
+impl Distribution<bool> for Bernoulli {
+    #[inline(always)]
+    fn sample<R: Random>(&self, rng: &R) -> bool {
+        // p == 1 doesn't consume an output.
+        self.threshold == Self::ALWAYS || rng.u64() < self.threshold
+    }
+}
+
+/// The number of failures before the first success of Bernoulli trials with
+/// probability `p`, sampled by inversion.
+#[derive(Clone, Copy, Debug)]
+pub struct Geometric {
+    p: f64,
+    /// `1 / ln(1 - p)`
+    scale: f64,
+}
+
+impl Geometric {
+    /// Returns `None` if `p` isn't in `(0, 1]`.
+    pub fn new(p: f64) -> Option<Self> {
+        (p > 0.0 && p <= 1.0).then_some(Self {
+            p,
+            scale: 1.0 / (-p).ln_1p(),
+        })
+    }
+}
+
+impl Distribution<u64> for Geometric {
+    #[inline(always)]
+    fn sample<R: Random>(&self, rng: &R) -> u64 {
+        if self.p == 1.0 {
+            return 0;
+        }
+        // Saturates for extremely small p.
+        (open01(rng).ln() * self.scale).floor() as u64
+    }
+}
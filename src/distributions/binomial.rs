@@ -0,0 +1,230 @@
+use std::cmp::Ordering;
+
+use super::{open01, Distribution};
+use crate::Random;
+
+/// The number of successes in `n` Bernoulli trials with probability `p`.
+///
+/// Uses inversion (BINV) for `n * p < 10` and otherwise BTPE from Voratas
+/// Kachitvichyanukul and Bruce W. Schmeiser, Binomial Random Variate
+/// Generation. Communications of the ACM 31.2 (1988): 216-222.
+#[derive(Clone, Copy, Debug)]
+pub struct Binomial {
+    method: Method,
+    /// Samples are taken for `1 - p` if `p > 0.5`.
+    flipped: bool,
+    n: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Method {
+    Constant(u64),
+    Inversion { r: f64, s: f64, a: f64 },
+    Btpe(Btpe),
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Btpe {
+    p: f64,
+    q: f64,
+    npq: f64,
+    m: i64,
+    x_m: f64,
+    x_l: f64,
+    x_r: f64,
+    c: f64,
+    lambda_l: f64,
+    lambda_r: f64,
+    p1: f64,
+    p2: f64,
+    p3: f64,
+    p4: f64,
+}
+
+impl Binomial {
+    const INVERSION_THRESHOLD: f64 = 10.0;
+
+    /// Returns `None` if `p` isn't in `[0, 1]`.
+    pub fn new(n: u64, p: f64) -> Option<Self> {
+        if !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+
+        let flipped = p > 0.5;
+        let p = if flipped { 1.0 - p } else { p };
+        let q = 1.0 - p;
+        let np = n as f64 * p;
+
+        let method = if p == 0.0 {
+            Method::Constant(0)
+        } else if np < Self::INVERSION_THRESHOLD {
+            let s = p / q;
+            Method::Inversion {
+                r: q.powf(n as f64),
+                s,
+                a: (n as f64 + 1.0) * s,
+            }
+        } else {
+            let npq = np * q;
+            let p1 = (2.195 * npq.sqrt() - 4.6 * q).floor() + 0.5;
+            let m = (np + p) as i64;
+            let x_m = m as f64 + 0.5;
+            let x_l = x_m - p1;
+            let x_r = x_m + p1;
+            let c = 0.134 + 20.5 / (15.3 + m as f64);
+            let lambda = |a: f64| a * (1.0 + 0.5 * a);
+            let lambda_l = lambda((np + p - x_l) / (np + p - x_l * p));
+            let lambda_r = lambda((x_r - np - p) / (x_r * q));
+            let p2 = p1 * (1.0 + 2.0 * c);
+            let p3 = p2 + c / lambda_l;
+            let p4 = p3 + c / lambda_r;
+
+            Method::Btpe(Btpe {
+                p,
+                q,
+                npq,
+                m,
+                x_m,
+                x_l,
+                x_r,
+                c,
+                lambda_l,
+                lambda_r,
+                p1,
+                p2,
+                p3,
+                p4,
+            })
+        };
+
+        Some(Self { method, flipped, n })
+    }
+
+    fn inversion<R: Random>(&self, rng: &R, r: f64, s: f64, a: f64) -> u64 {
+        // Restarts on rounding errors far out in the tail.
+        const MAX_X: u64 = 110;
+
+        'outer: loop {
+            let mut r = r;
+            let mut u = rng.f64();
+            let mut x = 0;
+
+            while u > r {
+                u -= r;
+                x += 1;
+                if x > MAX_X {
+                    continue 'outer;
+                }
+                r *= a / x as f64 - s;
+            }
+            return x;
+        }
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn btpe<R: Random>(&self, rng: &R, b: &Btpe) -> u64 {
+        const SQUEEZE_THRESHOLD: i64 = 20;
+
+        let n = self.n as f64;
+        loop {
+            let u = rng.f64() * b.p4;
+            let mut v = open01(rng);
+
+            // Triangular region, accepted right away.
+            if u <= b.p1 {
+                return (b.x_m - b.p1 * v + u) as u64;
+            }
+
+            let y = if u <= b.p2 {
+                // Parallelograms.
+                let x = b.x_l + (u - b.p1) / b.c;
+                v = v * b.c + 1.0 - (x - b.x_m).abs() / b.p1;
+                if v > 1.0 {
+                    continue;
+                }
+                x.floor() as i64
+            } else if u <= b.p3 {
+                // Left exponential tail.
+                let y = (b.x_l + v.ln() / b.lambda_l).floor() as i64;
+                if y < 0 {
+                    continue;
+                }
+                v *= (u - b.p2) * b.lambda_l;
+                y
+            } else {
+                // Right exponential tail.
+                let y = (b.x_r - v.ln() / b.lambda_r).floor() as i64;
+                if y > self.n as i64 {
+                    continue;
+                }
+                v *= (u - b.p3) * b.lambda_r;
+                y
+            };
+
+            let k = (y - b.m).abs();
+            if k <= SQUEEZE_THRESHOLD || k as f64 >= 0.5 * b.npq - 1.0 {
+                // Explicit evaluation of f(y) / f(m) through the recurrence.
+                let s = b.p / b.q;
+                let a = s * (n + 1.0);
+                let mut f = 1.0;
+                match b.m.cmp(&y) {
+                    Ordering::Less => (b.m + 1..=y).for_each(|i| f *= a / i as f64 - s),
+                    Ordering::Greater => (y + 1..=b.m).for_each(|i| f /= a / i as f64 - s),
+                    Ordering::Equal => {}
+                }
+                if v <= f {
+                    return y as u64;
+                }
+                continue;
+            }
+
+            // Squeeze using the upper and lower bounds of ln(f(y)).
+            let k = k as f64;
+            let rho = (k / b.npq) * ((k * (k / 3.0 + 0.625) + 1.0 / 6.0) / b.npq + 0.5);
+            let t = -0.5 * k * k / b.npq;
+            let alpha = v.ln();
+            if alpha < t - rho {
+                return y as u64;
+            }
+            if alpha > t + rho {
+                continue;
+            }
+
+            // Final acceptance with Stirling's formula.
+            let stirling = |a: f64| {
+                let a2 = a * a;
+                (13860.0 - (462.0 - (132.0 - (99.0 - 140.0 / a2) / a2) / a2) / a2) / a / 166320.0
+            };
+            let x1 = (y + 1) as f64;
+            let f1 = (b.m + 1) as f64;
+            let z = n + 1.0 - b.m as f64;
+            let w = n - y as f64 + 1.0;
+            let bound = b.x_m * (f1 / x1).ln()
+                + (n - b.m as f64 + 0.5) * (z / w).ln()
+                + (y - b.m) as f64 * (w * b.p / (x1 * b.q)).ln()
+                + stirling(f1)
+                + stirling(z)
+                - stirling(x1)
+                - stirling(w);
+            if alpha <= bound {
+                return y as u64;
+            }
+        }
+    }
+}
+
+impl Distribution<u64> for Binomial {
+    fn sample<R: Random>(&self, rng: &R) -> u64 {
+        let x = match &self.method {
+            Method::Constant(x) => *x,
+            Method::Inversion { r, s, a } => self.inversion(rng, *r, *s, *a),
+            Method::Btpe(btpe) => self.btpe(rng, btpe),
+        };
+
+        if self.flipped {
+            self.n - x
+        } else {
+            x
+        }
+    }
+}
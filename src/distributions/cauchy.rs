@@ -0,0 +1,25 @@
+use std::f64::consts::PI;
+
+use super::{open01, Distribution};
+use crate::Random;
+
+/// The Cauchy distribution, sampled by inverting its CDF.
+#[derive(Clone, Copy, Debug)]
+pub struct Cauchy {
+    median: f64,
+    scale: f64,
+}
+
+impl Cauchy {
+    /// Returns `None` if `scale` isn't positive and finite.
+    pub fn new(median: f64, scale: f64) -> Option<Self> {
+        (median.is_finite() && scale > 0.0 && scale.is_finite()).then_some(Self { median, scale })
+    }
+}
+
+impl Distribution<f64> for Cauchy {
+    #[inline(always)]
+    fn sample<R: Random>(&self, rng: &R) -> f64 {
+        self.median + self.scale * (PI * (open01(rng) - 0.5)).tan()
+    }
+}
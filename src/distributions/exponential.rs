@@ -0,0 +1,42 @@
+use super::{open01, ziggurat, Distribution};
+use crate::Random;
+
+/// The exponential distribution with rate 1.
+#[derive(Clone, Copy, Debug)]
+pub struct Exp1;
+
+impl Distribution<f64> for Exp1 {
+    #[inline(always)]
+    fn sample<R: Random>(&self, rng: &R) -> f64 {
+        ziggurat::sample(
+            rng,
+            ziggurat::exp_tables(),
+            false,
+            ziggurat::exp_pdf,
+            // The distribution is memoryless, so the tail is just shifted.
+            |rng, _| ziggurat::EXP_R - open01(rng).ln(),
+        )
+    }
+}
+
+/// The exponential distribution with rate `lambda`.
+#[derive(Clone, Copy, Debug)]
+pub struct Exp {
+    lambda_inverse: f64,
+}
+
+impl Exp {
+    /// Returns `None` if `lambda` isn't positive.
+    pub fn new(lambda: f64) -> Option<Self> {
+        (lambda > 0.0).then_some(Self {
+            lambda_inverse: 1.0 / lambda,
+        })
+    }
+}
+
+impl Distribution<f64> for Exp {
+    #[inline(always)]
+    fn sample<R: Random>(&self, rng: &R) -> f64 {
+        Exp1.sample(rng) * self.lambda_inverse
+    }
+}
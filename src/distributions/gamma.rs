@@ -0,0 +1,133 @@
+use super::{open01, Distribution, StandardNormal};
+use crate::Random;
+
+/// The gamma distribution with the given shape `k` and scale `theta`.
+///
+/// George Marsaglia and Wai Wan Tsang, A Simple Method for Generating Gamma
+/// Variables. ACM Transactions on Mathematical Software 26.3 (2000): 363-372.
+#[derive(Clone, Copy, Debug)]
+pub struct Gamma {
+    shape: f64,
+    scale: f64,
+    d: f64,
+    c: f64,
+}
+
+impl Gamma {
+    /// Returns `None` if `shape` or `scale` aren't positive and finite.
+    pub fn new(shape: f64, scale: f64) -> Option<Self> {
+        if !(shape > 0.0 && shape.is_finite() && scale > 0.0 && scale.is_finite()) {
+            return None;
+        }
+
+        // Shapes below 1 are sampled as Gamma(shape + 1) * U^(1 / shape).
+        let boosted = if shape < 1.0 { shape + 1.0 } else { shape };
+        let d = boosted - 1.0 / 3.0;
+        Some(Self {
+            shape,
+            scale,
+            d,
+            c: 1.0 / (9.0 * d).sqrt(),
+        })
+    }
+
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Samples from Gamma(shape, 1), or Gamma(shape + 1, 1) for shapes below 1.
+    #[inline(always)]
+    fn sample_unscaled<R: Random>(&self, rng: &R) -> f64 {
+        loop {
+            let x = StandardNormal.sample(rng);
+            let v = 1.0 + self.c * x;
+            if v <= 0.0 {
+                continue;
+            }
+
+            let v = v * v * v;
+            let u = open01(rng);
+            let x2 = x * x;
+            if u < 1.0 - 0.0331 * x2 * x2 || u.ln() < 0.5 * x2 + self.d * (1.0 - v + v.ln()) {
+                return self.d * v;
+            }
+        }
+    }
+}
+
+impl Distribution<f64> for Gamma {
+    #[inline(always)]
+    fn sample<R: Random>(&self, rng: &R) -> f64 {
+        let x = self.sample_unscaled(rng);
+        if self.shape < 1.0 {
+            x * open01(rng).powf(1.0 / self.shape) * self.scale
+        } else {
+            x * self.scale
+        }
+    }
+}
+
+/// The beta distribution, sampled as `X / (X + Y)` with `X ~ Gamma(alpha, 1)`
+/// and `Y ~ Gamma(beta, 1)`.
+///
+/// If both shapes are below 1, both gamma variates underflow to 0 too often,
+/// so these use Jöhnk's algorithm instead, in log space where `U^(1 / alpha)`
+/// underflows as well.
+///
+/// M. D. Jöhnk, Erzeugung von betaverteilten und gammaverteilten
+/// Zufallszahlen. Metrika 8 (1964): 5-15.
+#[derive(Clone, Copy, Debug)]
+pub struct Beta {
+    alpha: Gamma,
+    beta: Gamma,
+}
+
+impl Beta {
+    /// Returns `None` if `alpha` or `beta` aren't positive and finite.
+    pub fn new(alpha: f64, beta: f64) -> Option<Self> {
+        Some(Self {
+            alpha: Gamma::new(alpha, 1.0)?,
+            beta: Gamma::new(beta, 1.0)?,
+        })
+    }
+
+    #[inline(always)]
+    fn johnk<R: Random>(&self, rng: &R) -> f64 {
+        let (alpha, beta) = (self.alpha.shape(), self.beta.shape());
+        loop {
+            let log_x = open01(rng).ln() / alpha;
+            let log_y = open01(rng).ln() / beta;
+            let x = log_x.exp();
+            let y = log_y.exp();
+            if x + y > 1.0 {
+                continue;
+            }
+            if x + y > 0.0 {
+                return x / (x + y);
+            }
+
+            // Scales both by the larger one, which keeps the ratio.
+            let log_m = log_x.max(log_y);
+            let x = (log_x - log_m).exp();
+            let y = (log_y - log_m).exp();
+            return x / (x + y);
+        }
+    }
+}
+
+impl Distribution<f64> for Beta {
+    #[inline(always)]
+    fn sample<R: Random>(&self, rng: &R) -> f64 {
+        if self.alpha.shape() < 1.0 && self.beta.shape() < 1.0 {
+            return self.johnk(rng);
+        }
+
+        let x = self.alpha.sample(rng);
+        let y = self.beta.sample(rng);
+        x / (x + y)
+    }
+}
@@ -0,0 +1,316 @@
+use std::cmp::Ordering;
+
+use super::{ln_gamma, open01, Distribution};
+use crate::Random;
+
+/// The number of successes when drawing `draws` items without replacement
+/// from a population of `total` items that contains `successes` successes.
+///
+/// For a standard deviation below 10, samples by inversion that starts at the
+/// mode and alternately walks down and up, which costs O(standard deviation)
+/// steps. Otherwise uses rejection from a rectangle around the mode with
+/// exponential tails like H2PE from Voratas Kachitvichyanukul and Bruce W.
+/// Schmeiser, Computer Generation of Hypergeometric Random Variates. Journal of
+/// Statistical Computation and Simulation 22.2 (1985): 127-145.
+///
+/// Probabilities far from the mode are computed like R's `dhyper`, from three
+/// binomial probabilities with Loader's saddle point expansion, which stays
+/// accurate for huge populations where differences of `ln_gamma` lose all
+/// digits.
+#[derive(Clone, Copy, Debug)]
+pub struct Hypergeometric {
+    method: Method,
+    total: u64,
+    successes: u64,
+    draws: u64,
+    min: u64,
+    max: u64,
+    mode: u64,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Method {
+    Constant,
+    Inversion { p_mode: f64 },
+    H2pe(H2pe),
+}
+
+/// The hat is 1 over the rectangle `mode - left..=mode + right` and falls off
+/// exponentially beyond, starting from the probabilities next to the
+/// rectangle. All probabilities are relative to the one of the mode.
+#[derive(Clone, Copy, Debug)]
+struct H2pe {
+    p: f64,
+    q: f64,
+    ln_f_mode: f64,
+    left: u64,
+    right: u64,
+    lambda_l: f64,
+    lambda_r: f64,
+    p1: f64,
+    p2: f64,
+    p3: f64,
+}
+
+impl Hypergeometric {
+    const INVERSION_THRESHOLD: f64 = 10.0;
+
+    /// Returns `None` if `successes` or `draws` exceed `total`.
+    pub fn new(total: u64, successes: u64, draws: u64) -> Option<Self> {
+        if successes > total || draws > total {
+            return None;
+        }
+
+        let min = draws.saturating_sub(total - successes);
+        let max = draws.min(successes);
+        // Saturates only if everything is `u64::MAX`, where the result still fits.
+        let mode = ((draws as u128 + 1).saturating_mul(successes as u128 + 1) / (total as u128 + 2))
+            as u64;
+        let mut s = Self {
+            method: Method::Constant,
+            total,
+            successes,
+            draws,
+            min,
+            max,
+            mode: mode.clamp(min, max),
+        };
+        if min == max {
+            return Some(s);
+        }
+
+        // Any p works, as it cancels out. draws / total keeps the terms small.
+        let p = draws as f64 / total as f64;
+        let q = (total - draws) as f64 / total as f64;
+        let ln_f_mode = s.ln_f(s.mode, p, q);
+
+        let variance = draws as f64
+            * (successes as f64 / total as f64)
+            * ((total - successes) as f64 / total as f64)
+            * ((total - draws) as f64 / (total - 1) as f64);
+        let sd = variance.sqrt();
+        if sd < Self::INVERSION_THRESHOLD {
+            let p_mode = (ln_f_mode - ln_binomial(draws, total, p, q)).exp();
+            s.method = Method::Inversion { p_mode };
+            return Some(s);
+        }
+
+        let d = (1.5 * sd + 0.5) as u64;
+        let left = d.min(s.mode - min);
+        let right = d.min(max - s.mode);
+        // The ratios of neighboring probabilities shrink from left to right, so
+        // the ratio at the edge of the rectangle bounds every step further out.
+        let (lambda_l, k_l) = match s.mode - left {
+            edge if edge > min => (
+                s.ratio_up(edge - 1).ln(),
+                (s.ln_f(edge - 1, p, q) - ln_f_mode).exp(),
+            ),
+            _ => (1.0, 0.0),
+        };
+        let (lambda_r, k_r) = match s.mode + right {
+            edge if edge < max => (
+                -s.ratio_up(edge).ln(),
+                (s.ln_f(edge + 1, p, q) - ln_f_mode).exp(),
+            ),
+            _ => (1.0, 0.0),
+        };
+        let p1 = (left + right + 1) as f64;
+        let p2 = p1 + k_l * lambda_l.exp() / lambda_l;
+        let p3 = p2 + k_r * lambda_r.exp() / lambda_r;
+
+        s.method = Method::H2pe(H2pe {
+            p,
+            q,
+            ln_f_mode,
+            left,
+            right,
+            lambda_l,
+            lambda_r,
+            p1,
+            p2,
+            p3,
+        });
+        Some(s)
+    }
+
+    /// p(k + 1) / p(k)
+    fn ratio_up(&self, k: u64) -> f64 {
+        let failures = self.total - self.successes;
+        ((self.successes - k) as f64 * (self.draws - k) as f64)
+            / ((k + 1) as f64 * (failures + k + 1 - self.draws) as f64)
+    }
+
+    /// `ln(p(k))` up to a constant that only depends on `p`.
+    fn ln_f(&self, k: u64, p: f64, q: f64) -> f64 {
+        ln_binomial(k, self.successes, p, q)
+            + ln_binomial(self.draws - k, self.total - self.successes, p, q)
+    }
+
+    fn inversion<R: Random>(&self, rng: &R, p_mode: f64) -> u64 {
+        loop {
+            let mut u = rng.f64() - p_mode;
+            if u < 0.0 {
+                return self.mode;
+            }
+
+            let (mut low, mut p_low) = (self.mode, p_mode);
+            let (mut high, mut p_high) = (self.mode, p_mode);
+            // A side whose probability underflowed can't take up the rest of `u`.
+            while (low > self.min && p_low > 0.0) || (high < self.max && p_high > 0.0) {
+                if low > self.min && p_low > 0.0 {
+                    low -= 1;
+                    p_low /= self.ratio_up(low);
+                    u -= p_low;
+                    if u < 0.0 {
+                        return low;
+                    }
+                }
+                if high < self.max && p_high > 0.0 {
+                    p_high *= self.ratio_up(high);
+                    high += 1;
+                    u -= p_high;
+                    if u < 0.0 {
+                        return high;
+                    }
+                }
+            }
+            // Only reached through rounding errors; try again.
+        }
+    }
+
+    fn h2pe<R: Random>(&self, rng: &R, h: &H2pe) -> u64 {
+        const RECURRENCE_THRESHOLD: u64 = 20;
+
+        loop {
+            let u = rng.f64() * h.p3;
+            let mut v = open01(rng);
+
+            // Offsets from the mode, which keep their precision in huge populations.
+            let x = if u < h.p1 {
+                // Rectangle.
+                u - h.left as f64
+            } else if u < h.p2 {
+                // Left exponential tail.
+                let x = v.ln() / h.lambda_l - h.left as f64;
+                v *= (u - h.p1) * h.lambda_l;
+                x
+            } else {
+                // Right exponential tail.
+                let x = (h.right + 1) as f64 - v.ln() / h.lambda_r;
+                v *= (u - h.p2) * h.lambda_r;
+                x
+            };
+
+            let x = x.floor();
+            let y = if x < 0.0 {
+                if -x > (self.mode - self.min) as f64 {
+                    continue;
+                }
+                self.mode - (-x) as u64
+            } else {
+                if x > (self.max - self.mode) as f64 {
+                    continue;
+                }
+                self.mode + x as u64
+            };
+
+            // Explicit evaluation of f(y) / f(mode) through the recurrence
+            // close to the mode, and with the saddle point expansion further out.
+            if y.abs_diff(self.mode) <= RECURRENCE_THRESHOLD {
+                let mut f = 1.0;
+                match self.mode.cmp(&y) {
+                    Ordering::Less => (self.mode..y).for_each(|k| f *= self.ratio_up(k)),
+                    Ordering::Greater => (y..self.mode).for_each(|k| f /= self.ratio_up(k)),
+                    Ordering::Equal => {}
+                }
+                if v <= f {
+                    return y;
+                }
+            } else if v.ln() <= self.ln_f(y, h.p, h.q) - h.ln_f_mode {
+                return y;
+            }
+        }
+    }
+}
+
+impl Distribution<u64> for Hypergeometric {
+    fn sample<R: Random>(&self, rng: &R) -> u64 {
+        match &self.method {
+            Method::Constant => self.mode,
+            Method::Inversion { p_mode } => self.inversion(rng, *p_mode),
+            Method::H2pe(h2pe) => self.h2pe(rng, h2pe),
+        }
+    }
+}
+
+/// `ln(Gamma(n + 1)) - (n + 0.5) ln(n) + n - ln(sqrt(2 pi))`, the error of
+/// Stirling's formula for `n!`.
+fn stirling_error(n: f64) -> f64 {
+    const LN_SQRT_2PI: f64 = 0.9189385332046728;
+
+    if n == 0.0 {
+        return 0.0;
+    }
+    if n <= 15.0 {
+        return ln_gamma(n + 1.0) - (n + 0.5) * n.ln() + n - LN_SQRT_2PI;
+    }
+
+    let n2 = n * n;
+    (1.0 / 12.0
+        - (1.0 / 360.0 - (1.0 / 1260.0 - (1.0 / 1680.0 - 1.0 / 1188.0 / n2) / n2) / n2) / n2)
+        / n
+}
+
+/// The deviance term `x ln(x / np) + np - x`, with a series for `x` close to
+/// `np`, where the direct formula cancels.
+fn deviance(x: f64, np: f64) -> f64 {
+    if (x - np).abs() < 0.1 * (x + np) {
+        let v = (x - np) / (x + np);
+        let mut sum = (x - np) * v;
+        let mut term = 2.0 * x * v;
+        let v2 = v * v;
+        for j in 1.. {
+            term *= v2;
+            let next = sum + term / (2 * j + 1) as f64;
+            if next == sum {
+                break;
+            }
+            sum = next;
+        }
+        return sum;
+    }
+    x * (x / np).ln() + np - x
+}
+
+/// `ln(P(X = k))` for `X ~ Binomial(n, p)`, with `q = 1 - p` passed separately
+/// so that it keeps its precision.
+///
+/// Catherine Loader, Fast and Accurate Computation of Binomial Probabilities.
+/// (2000).
+fn ln_binomial(k: u64, n: u64, p: f64, q: f64) -> f64 {
+    const LN_2PI: f64 = 1.8378770664093453;
+
+    let (k, n) = (k as f64, n as f64);
+    if k == 0.0 {
+        return if p < 0.1 {
+            -deviance(n, n * q) - n * p
+        } else {
+            n * q.ln()
+        };
+    }
+    if k == n {
+        return if q < 0.1 {
+            -deviance(n, n * p) - n * q
+        } else {
+            n * p.ln()
+        };
+    }
+
+    let lc = stirling_error(n)
+        - stirling_error(k)
+        - stirling_error(n - k)
+        - deviance(k, n * p)
+        - deviance(n - k, n * q);
+    let lf = LN_2PI + k.ln() + (-k / n).ln_1p();
+    lc - 0.5 * lf
+}
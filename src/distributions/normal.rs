@@ -0,0 +1,84 @@
+use super::{open01, ziggurat, Distribution};
+use crate::Random;
+
+/// The normal distribution with mean 0 and standard deviation 1.
+#[derive(Clone, Copy, Debug)]
+pub struct StandardNormal;
+
+impl Distribution<f64> for StandardNormal {
+    #[inline(always)]
+    fn sample<R: Random>(&self, rng: &R) -> f64 {
+        ziggurat::sample(
+            rng,
+            ziggurat::normal_tables(),
+            true,
+            ziggurat::normal_pdf,
+            |rng, u| {
+                // Marsaglia's tail method.
+                let mut x;
+                loop {
+                    x = open01(rng).ln() / ziggurat::NORMAL_R;
+                    let y = open01(rng).ln();
+                    if -2.0 * y >= x * x {
+                        break;
+                    }
+                }
+                if u < 0.0 {
+                    x - ziggurat::NORMAL_R
+                } else {
+                    ziggurat::NORMAL_R - x
+                }
+            },
+        )
+    }
+}
+
+/// The normal distribution `N(mean, std_dev^2)`.
+#[derive(Clone, Copy, Debug)]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
+
+impl Normal {
+    /// Returns `None` if `std_dev` is negative or not finite.
+    pub fn new(mean: f64, std_dev: f64) -> Option<Self> {
+        (mean.is_finite() && std_dev.is_finite() && std_dev >= 0.0)
+            .then_some(Self { mean, std_dev })
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.std_dev
+    }
+}
+
+impl Distribution<f64> for Normal {
+    #[inline(always)]
+    fn sample<R: Random>(&self, rng: &R) -> f64 {
+        self.mean + self.std_dev * StandardNormal.sample(rng)
+    }
+}
+
+/// The distribution of `exp(X)` with `X ~ N(mu, sigma^2)`.
+#[derive(Clone, Copy, Debug)]
+pub struct LogNormal {
+    normal: Normal,
+}
+
+impl LogNormal {
+    /// Returns `None` if `sigma` is negative or not finite.
+    pub fn new(mu: f64, sigma: f64) -> Option<Self> {
+        Normal::new(mu, sigma).map(|normal| Self { normal })
+    }
+}
+
+impl Distribution<f64> for LogNormal {
+    #[inline(always)]
+    fn sample<R: Random>(&self, rng: &R) -> f64 {
+        self.normal.sample(rng).exp()
+    }
+}
@@ -0,0 +1,99 @@
+use super::{ln_gamma, Distribution};
+use crate::Random;
+
+/// The Poisson distribution with mean `lambda`.
+///
+/// Uses Knuth's multiplication method for `lambda < 10` and otherwise PTRS
+/// from Wolfgang Hörmann, The transformed rejection method for generating
+/// Poisson random variables. Insurance: Mathematics and Economics 12.1 (1993):
+/// 39-45.
+#[derive(Clone, Copy, Debug)]
+pub struct Poisson {
+    method: Method,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Method {
+    Multiplication {
+        exp_minus_lambda: f64,
+    },
+    Ptrs {
+        lambda: f64,
+        ln_lambda: f64,
+        a: f64,
+        b: f64,
+        ln_inv_alpha: f64,
+        v_r: f64,
+    },
+}
+
+impl Poisson {
+    const PTRS_THRESHOLD: f64 = 10.0;
+
+    /// Returns `None` if `lambda` isn't positive, or too large for the result
+    /// to fit into a `u64`.
+    pub fn new(lambda: f64) -> Option<Self> {
+        if !(lambda > 0.0 && lambda < 1e18) {
+            return None;
+        }
+
+        let method = if lambda < Self::PTRS_THRESHOLD {
+            Method::Multiplication {
+                exp_minus_lambda: (-lambda).exp(),
+            }
+        } else {
+            let b = 0.931 + 2.53 * lambda.sqrt();
+            Method::Ptrs {
+                lambda,
+                ln_lambda: lambda.ln(),
+                a: -0.059 + 0.02483 * b,
+                b,
+                ln_inv_alpha: (1.1239 + 1.1328 / (b - 3.4)).ln(),
+                v_r: 0.9277 - 3.6224 / (b - 2.0),
+            }
+        };
+
+        Some(Self { method })
+    }
+}
+
+impl Distribution<u64> for Poisson {
+    fn sample<R: Random>(&self, rng: &R) -> u64 {
+        match self.method {
+            Method::Multiplication { exp_minus_lambda } => {
+                let mut k = 0;
+                let mut product = rng.f64();
+                while product > exp_minus_lambda {
+                    product *= rng.f64();
+                    k += 1;
+                }
+                k
+            }
+            Method::Ptrs {
+                lambda,
+                ln_lambda,
+                a,
+                b,
+                ln_inv_alpha,
+                v_r,
+            } => loop {
+                let u = rng.f64() - 0.5;
+                let v = rng.f64();
+                let us = 0.5 - u.abs();
+                let k = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+
+                if us >= 0.07 && v <= v_r {
+                    return k as u64;
+                }
+                if k < 0.0 || (us < 0.013 && v > us) {
+                    continue;
+                }
+                if v.ln() + ln_inv_alpha - (a / (us * us) + b).ln()
+                    <= -lambda + k * ln_lambda - ln_gamma(k + 1.0)
+                {
+                    return k as u64;
+                }
+            },
+        }
+    }
+}
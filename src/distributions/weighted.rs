@@ -0,0 +1,226 @@
+use super::Distribution;
+use crate::Random;
+
+/// Samples indices by weight in O(1) with Walker's alias method, built with
+/// Vose's algorithm.
+///
+/// Michael D. Vose, A Linear Algorithm for Generating Random Numbers with a
+/// Given Distribution. IEEE Transactions on Software Engineering 17.9 (1991):
+/// 972-975.
+///
+/// Every column stores the probability of keeping its own index as a fraction
+/// of 2^64, so sampling takes an index and one integer comparison.
+#[derive(Clone, Debug)]
+pub struct AliasTable {
+    threshold: Vec<u64>,
+    alias: Vec<u32>,
+}
+
+impl AliasTable {
+    /// Returns `None` if `weights` is empty, longer than `u32::MAX`, contains
+    /// negative or non-finite weights or sums up to 0.
+    pub fn new(weights: &[f64]) -> Option<Self> {
+        if weights.iter().any(|w| !(w.is_finite() && *w >= 0.0)) {
+            return None;
+        }
+        let total: f64 = weights.iter().sum();
+        if !(total > 0.0 && total.is_finite()) {
+            return None;
+        }
+
+        let n = weights.len() as f64;
+        Self::vose(weights.iter().map(|w| w * n / total).collect(), 1.0, |p| {
+            (p * 18446744073709551616.0) as u64
+        })
+    }
+
+    /// Builds the table with integer arithmetic, so the probabilities are exact
+    /// up to the resolution of 2^-64.
+    ///
+    /// Returns `None` if `weights` is empty, longer than `u32::MAX` or sums up
+    /// to 0 or more than `u64::MAX`.
+    pub fn from_integer_weights(weights: &[u64]) -> Option<Self> {
+        let total = weights
+            .iter()
+            .try_fold(0u64, |sum, &w| sum.checked_add(w))?;
+        if total == 0 {
+            return None;
+        }
+        let total = total as u128;
+
+        // Every column has a capacity of `total`, and there are n columns.
+        let n = weights.len() as u128;
+        Self::vose(
+            weights.iter().map(|&w| w as u128 * n).collect(),
+            total,
+            |p| ((p << 64) / total) as u64,
+        )
+    }
+
+    /// `scaled` contains the weights scaled so a full column has the size
+    /// `full`. `to_threshold` converts a column's remainder into a fraction
+    /// of 2^64.
+    fn vose<T>(mut scaled: Vec<T>, full: T, to_threshold: impl Fn(T) -> u64) -> Option<Self>
+    where
+        T: Copy + PartialOrd + std::ops::Add<Output = T> + std::ops::Sub<Output = T>,
+    {
+        if scaled.is_empty() || scaled.len() > u32::MAX as usize {
+            return None;
+        }
+
+        let len = scaled.len();
+        let mut threshold = vec![u64::MAX; len];
+        let mut alias: Vec<u32> = (0..len as u32).collect();
+
+        let (mut small, mut large): (Vec<u32>, Vec<u32>) =
+            (0..len as u32).partition(|&i| scaled[i as usize] < full);
+
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            let (s, l) = (s as usize, l as usize);
+
+            // The small column keeps its own weight, the large one fills the rest.
+            threshold[s] = to_threshold(scaled[s]);
+            alias[s] = l as u32;
+
+            scaled[l] = scaled[l] + scaled[s] - full;
+            if scaled[l] < full {
+                large.pop();
+                small.push(l as u32);
+            }
+        }
+
+        // Whatever is left over is full up to rounding errors and keeps the
+        // default threshold, with the column aliasing itself.
+        Some(Self { threshold, alias })
+    }
+
+    pub fn len(&self) -> usize {
+        self.threshold.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.threshold.is_empty()
+    }
+}
+
+impl Distribution<usize> for AliasTable {
+    #[inline(always)]
+    fn sample<R: Random>(&self, rng: &R) -> usize {
+        let i = rng.bounded_u64(self.threshold.len() as u64) as usize;
+        if rng.u64() < self.threshold[i] {
+            i
+        } else {
+            self.alias[i] as usize
+        }
+    }
+}
+
+/// Samples indices by weight with a binary search over the cumulative weights.
+///
+/// Slower than `AliasTable` with O(log n) per sample, but weights can be
+/// changed without rebuilding everything.
+#[derive(Clone, Debug)]
+pub struct CumulativeWeights {
+    weights: Vec<f64>,
+    cumulative: Vec<f64>,
+}
+
+impl CumulativeWeights {
+    /// Returns `None` if `weights` is empty, contains negative or non-finite
+    /// weights or sums up to 0.
+    pub fn new(weights: &[f64]) -> Option<Self> {
+        if weights.iter().any(|w| !(w.is_finite() && *w >= 0.0)) {
+            return None;
+        }
+
+        let cumulative: Vec<f64> = weights
+            .iter()
+            .scan(0.0, |sum, w| {
+                *sum += w;
+                Some(*sum)
+            })
+            .collect();
+
+        let s = Self {
+            weights: weights.to_vec(),
+            cumulative,
+        };
+        (s.total() > 0.0 && s.total().is_finite()).then_some(s)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cumulative.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cumulative.is_empty()
+    }
+
+    pub fn total(&self) -> f64 {
+        self.cumulative.last().copied().unwrap_or(0.0)
+    }
+
+    /// Panics if `index` is out of range.
+    pub fn weight(&self, index: usize) -> f64 {
+        self.weights[index]
+    }
+
+    /// Appends a new weight in O(1). Returns `false` and leaves the weights
+    /// untouched if `weight` is negative or the total would not be finite.
+    pub fn push(&mut self, weight: f64) -> bool {
+        let total = self.total() + weight;
+        if !(weight >= 0.0 && total.is_finite()) {
+            return false;
+        }
+        self.weights.push(weight);
+        self.cumulative.push(total);
+        true
+    }
+
+    /// Replaces the weight at `index` in O(n). Returns `false` and leaves the
+    /// weights untouched if the weight is invalid or the total would not be
+    /// positive and finite.
+    ///
+    /// Panics if `index` is out of range.
+    pub fn update(&mut self, index: usize, weight: f64) -> bool {
+        if !(weight.is_finite() && weight >= 0.0) {
+            return false;
+        }
+
+        let start = match index {
+            0 => 0.0,
+            _ => self.cumulative[index - 1],
+        };
+        let total = self.weights[index + 1..]
+            .iter()
+            .fold(start + weight, |sum, w| sum + w);
+        if !(total > 0.0 && total.is_finite()) {
+            return false;
+        }
+
+        // Sums the suffix up again from the individual weights instead of
+        // shifting it by the difference, so every zero weight stays exactly 0.
+        self.weights[index] = weight;
+        let mut sum = start;
+        for (c, w) in self.cumulative[index..]
+            .iter_mut()
+            .zip(&self.weights[index..])
+        {
+            sum += w;
+            *c = sum;
+        }
+        true
+    }
+}
+
+impl Distribution<usize> for CumulativeWeights {
+    #[inline(always)]
+    fn sample<R: Random>(&self, rng: &R) -> usize {
+        let u = rng.f64() * self.total();
+        // Skips zero weights, since their cumulative weight equals the previous one.
+        self.cumulative
+            .partition_point(|&c| c <= u)
+            .min(self.cumulative.len() - 1)
+    }
+}
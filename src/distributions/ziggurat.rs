@@ -0,0 +1,90 @@
+//! George Marsaglia and Wai Wan Tsang, The Ziggurat Method for Generating
+//! Random Variables. Journal of Statistical Software 5.8 (2000): 1-7.
+//!
+//! Uses 256 layers with the layout of the tables of the rand crate: `x[0]` is
+//! the width of the base strip, `x[1]` the start of the tail and `x[256]` 0.
+
+use std::sync::OnceLock;
+
+use crate::Random;
+
+const LAYERS: usize = 256;
+
+pub(crate) const NORMAL_R: f64 = 3.654152885361009;
+const NORMAL_V: f64 = 0.00492867323399;
+
+pub(crate) const EXP_R: f64 = 7.69711747013105;
+const EXP_V: f64 = 0.003949659822581557;
+
+pub(crate) struct Tables {
+    pub(crate) x: [f64; LAYERS + 1],
+    pub(crate) f: [f64; LAYERS + 1],
+}
+
+impl Tables {
+    fn new(r: f64, v: f64, pdf: fn(f64) -> f64, inverse_pdf: fn(f64) -> f64) -> Self {
+        let mut x = [0.0; LAYERS + 1];
+        x[0] = v / pdf(r);
+        x[1] = r;
+        for i in 2..LAYERS {
+            x[i] = inverse_pdf(v / x[i - 1] + pdf(x[i - 1]));
+        }
+        x[LAYERS] = 0.0;
+
+        Self { x, f: x.map(pdf) }
+    }
+}
+
+pub(crate) fn normal_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp()
+}
+
+pub(crate) fn exp_pdf(x: f64) -> f64 {
+    (-x).exp()
+}
+
+pub(crate) fn normal_tables() -> &'static Tables {
+    static TABLES: OnceLock<Tables> = OnceLock::new();
+    TABLES.get_or_init(|| Tables::new(NORMAL_R, NORMAL_V, normal_pdf, |y| (-2.0 * y.ln()).sqrt()))
+}
+
+pub(crate) fn exp_tables() -> &'static Tables {
+    static TABLES: OnceLock<Tables> = OnceLock::new();
+    TABLES.get_or_init(|| Tables::new(EXP_R, EXP_V, exp_pdf, |y| -y.ln()))
+}
+
+/// Samples from the ziggurat. `tail` is called with the uniform value of the
+/// base strip when the sample falls beyond `x[1]`.
+#[inline(always)]
+pub(crate) fn sample<R: Random>(
+    rng: &R,
+    tables: &Tables,
+    symmetric: bool,
+    pdf: fn(f64) -> f64,
+    tail: impl Fn(&R, f64) -> f64,
+) -> f64 {
+    loop {
+        // The low 8 bits select the layer, the upper 53 bits the position.
+        let bits = rng.u64();
+        let i = (bits & 0xFF) as usize;
+        let u = if symmetric {
+            // [-1, 1)
+            (bits >> 11) as f64 * (1.0 / (1u64 << 52) as f64) - 1.0
+        } else {
+            // (0, 1]
+            ((bits >> 11) + 1) as f64 * (1.0 / (1u64 << 53) as f64)
+        };
+
+        let x = u * tables.x[i];
+        let test_x = if symmetric { x.abs() } else { x };
+        if test_x < tables.x[i + 1] {
+            return x;
+        }
+        if i == 0 {
+            return tail(rng, u);
+        }
+        if tables.f[i + 1] + (tables.f[i] - tables.f[i + 1]) * rng.f64() < pdf(x) {
+            return x;
+        }
+    }
+}
@@ -2,6 +2,12 @@
 
 use std::cell::Cell;
 
+pub mod atomic;
+pub mod correlation;
+pub mod distributions;
+pub mod reduced;
+pub mod seq;
+
 #[cfg(feature = "nightly_thread_local")]
 mod nightly_tls;
 #[cfg(not(feature = "nightly_thread_local"))]
@@ -14,8 +20,47 @@ pub use stable_tls::*;
 
 pub trait Random {
     fn u64(&self) -> u64;
+
+    /// Seeds the generator from the operating system.
     fn seed(&self);
 
+    /// Returns the upper half of `u64()`, which has the better statistical
+    /// quality for most generators.
+    #[inline(always)]
+    fn u32(&self) -> u32 {
+        (self.u64() >> 32) as u32
+    }
+
+    /// Returns a uniform `f64` in `[0, 1)` built from the upper 53 bits of `u64()`.
+    #[inline(always)]
+    fn f64(&self) -> f64 {
+        (self.u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Returns a uniform value in `0..bound` without modulo bias.
+    ///
+    /// Uses Lemire's nearly divisionless method, which only needs a division
+    /// for the rare case that a sample has to be rejected.
+    ///
+    /// Panics if `bound` is 0, as the range is empty then.
+    #[inline(always)]
+    fn bounded_u64(&self, bound: u64) -> u64 {
+        assert!(bound != 0, "bound must not be 0");
+        let mut m = self.u64() as u128 * bound as u128;
+        if (m as u64) < bound {
+            let threshold = bound.wrapping_neg() % bound;
+            while (m as u64) < threshold {
+                m = self.u64() as u128 * bound as u128;
+            }
+        }
+        (m >> 64) as u64
+    }
+
+    /// Fills `slice` with consecutive outputs of `u64()` in native-endian
+    /// order. Each byte after the last full word takes the lowest byte of an
+    /// output of its own, so filling in pieces continues the same stream as
+    /// filling at once only if the pieces before the last are multiples of 8
+    /// bytes long.
     fn fill_bytes(&self, slice: &mut [u8]) {
         let mut chunks = slice.chunks_exact_mut(8);
         for chunk in &mut chunks {
@@ -28,10 +73,227 @@ pub trait Random {
     }
 }
 
-fn get_seed() -> u64 {
-    let mut buffer = [0u8; 8];
-    getrandom::getrandom(&mut buffer).expect("can't get seed");
-    u64::from_ne_bytes(buffer)
+/// Generators that can be seeded deterministically from a sequence of words.
+///
+/// Kept apart from `Random`, so that implementing `Random` only needs `u64()`
+/// and `seed()`, and `dyn Random` stays possible.
+pub trait SeedableRandom: Random {
+    /// The number of words `seed_from()` consumes.
+    const SEED_WORDS: usize;
+
+    /// Initializes the state from the words returned by `next`, fixing up
+    /// states the generator can't work with.
+    fn seed_from(&self, next: impl FnMut() -> u64);
+
+    /// Seeds the generator deterministically by expanding `seed` with SplitMix64.
+    fn seed_from_u64(&self, seed: u64) {
+        self.seed_from(splitmix_seeds(seed))
+    }
+}
+
+/// A generator that natively produces 32 bits per step.
+///
+/// Wrap it into `Widen` to use it as a `Random`.
+pub trait Random32 {
+    fn u32(&self) -> u32;
+
+    /// Seeds the generator from the operating system.
+    fn seed(&self);
+}
+
+/// The `SeedableRandom` of native 32-bit generators, which `Widen` passes on.
+pub trait SeedableRandom32: Random32 {
+    /// The number of words `seed_from()` consumes.
+    const SEED_WORDS: usize;
+
+    /// Initializes the state from the words returned by `next`, fixing up
+    /// states the generator can't work with.
+    fn seed_from(&self, next: impl FnMut() -> u64);
+
+    /// Seeds the generator deterministically by expanding `seed` with SplitMix64.
+    fn seed_from_u64(&self, seed: u64) {
+        self.seed_from(splitmix_seeds(seed))
+    }
+}
+
+/// Generators with an invertible state transition, which can step backwards
+/// through their stream.
+pub trait ReversibleRandom: Random {
+    /// Steps the state back and returns the output it produces, so this undoes
+    /// the last call to `u64()` and returns the same value again.
+    fn prev_u64(&self) -> u64;
+}
+
+/// Returns the inverse of the odd `a` modulo 2^128. Starts from `a`, which is
+/// its own inverse modulo 8, and doubles the correct bits with each Newton step.
+const fn inverse_u128(a: u128) -> u128 {
+    let mut x = a;
+    let mut i = 0;
+    while i < 6 {
+        x = x.wrapping_mul(2u128.wrapping_sub(a.wrapping_mul(x)));
+        i += 1;
+    }
+    x
+}
+
+/// Builds `u64()` from two consecutive outputs of a native 32-bit generator,
+/// the first one becoming the lower half.
+#[derive(Default)]
+pub struct Widen<R: Random32>(pub R);
+
+impl<R: Random32> Random for Widen<R> {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let low = self.0.u32() as u64;
+        let high = self.0.u32() as u64;
+        (high << 32) | low
+    }
+
+    fn seed(&self) {
+        self.0.seed()
+    }
+
+    #[inline(always)]
+    fn u32(&self) -> u32 {
+        self.0.u32()
+    }
+}
+
+impl<R: SeedableRandom32> SeedableRandom for Widen<R> {
+    const SEED_WORDS: usize = R::SEED_WORDS;
+
+    fn seed_from(&self, next: impl FnMut() -> u64) {
+        self.0.seed_from(next)
+    }
+}
+
+/// Caches one output of `R` and hands it out a few bits at a time, least
+/// significant bits first. Bits left over when a request doesn't fit are used
+/// as the low bits of the next value, so no output bit is wasted.
+#[derive(Default)]
+pub struct BitBuffer<R: Random> {
+    rng: R,
+    /// The unused bits, with everything above `remaining` cleared.
+    buffer: Cell<u64>,
+    remaining: Cell<u32>,
+}
+
+impl<R: Random> BitBuffer<R> {
+    pub fn new(rng: R) -> Self {
+        Self {
+            rng,
+            buffer: Cell::new(0),
+            remaining: Cell::new(0),
+        }
+    }
+
+    pub fn rng(&self) -> &R {
+        &self.rng
+    }
+
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+
+    /// Drops the buffered bits, so the next request starts with a fresh output.
+    pub fn clear(&self) {
+        self.buffer.set(0);
+        self.remaining.set(0);
+    }
+
+    #[inline(always)]
+    pub fn bool(&self) -> bool {
+        self.bits(1) != 0
+    }
+
+    /// Returns `n` random bits in the low bits of the result, for `n <= 64`.
+    #[inline(always)]
+    pub fn bits(&self, n: u32) -> u64 {
+        debug_assert!(n <= 64, "can't take more than 64 bits");
+        if n == 0 {
+            return 0;
+        }
+
+        let buffer = self.buffer.get();
+        let remaining = self.remaining.get();
+        if n <= remaining {
+            self.buffer.set(buffer.checked_shr(n).unwrap_or(0));
+            self.remaining.set(remaining - n);
+            return buffer & (u64::MAX >> (64 - n));
+        }
+
+        // Takes what's left and tops it up from a fresh output.
+        let fresh = self.rng.u64();
+        let needed = n - remaining;
+        self.buffer.set(fresh.checked_shr(needed).unwrap_or(0));
+        self.remaining.set(64 - needed);
+        let high = (fresh & (u64::MAX >> (64 - needed))) << remaining;
+        buffer | high
+    }
+
+    /// Returns a uniform value in `0..bound` by rejection sampling on the
+    /// fewest bits that can hold `bound - 1`. Takes less than two tries on
+    /// average, so small bounds only use a few bits.
+    ///
+    /// Panics if `bound` is 0, as no value can be accepted then.
+    #[inline(always)]
+    pub fn bounded(&self, bound: u64) -> u64 {
+        assert!(bound != 0, "bound must not be 0");
+        let n = 64 - (bound - 1).leading_zeros();
+        loop {
+            let x = self.bits(n);
+            if x < bound {
+                return x;
+            }
+        }
+    }
+}
+
+/// Upper bound of `SEED_WORDS` over all generators.
+const MAX_SEED_WORDS: usize = 16;
+
+/// Returns `count` words from the operating system, fetched with a single
+/// `getrandom` call.
+fn os_seeds(count: usize) -> impl FnMut() -> u64 {
+    assert!(count <= MAX_SEED_WORDS, "too many seed words");
+    let mut buffer = [0u8; MAX_SEED_WORDS * 8];
+    getrandom::getrandom(&mut buffer[..count * 8]).expect("can't get seed");
+
+    let mut index = 0;
+    move || {
+        assert!(index < count, "consumed more than SEED_WORDS words");
+        let word = &buffer[index * 8..][..8];
+        index += 1;
+        u64::from_ne_bytes(word.try_into().unwrap())
+    }
+}
+
+/// Expands `seed` into a stream of words with SplitMix64.
+fn splitmix_seeds(seed: u64) -> impl FnMut() -> u64 {
+    let rng = Splitmix64 {
+        state: Cell::new(seed),
+    };
+    move || rng.u64()
+}
+
+/// The first `N` words of `splitmix_seeds()` for `from_seed()`, as const
+/// functions can't call closures.
+const fn const_seeds<const N: usize>(seed: u64) -> [u64; N] {
+    let rng = Splitmix64 {
+        state: Cell::new(seed),
+    };
+    let mut words = [0; N];
+    let mut i = 0;
+    while i < N {
+        words[i] = rng.next_u64();
+        i += 1;
+    }
+    words
+}
+
+/// Combines two seed words like `seed_from()` does for 128-bit state words.
+const fn words_u128(high: u64, low: u64) -> u128 {
+    ((high as u128) << 64) + low as u128
 }
 
 /// Copyright 2020 Tyge Løvset.
@@ -42,6 +304,28 @@ pub struct Tylo64 {
     pub k: Cell<u64>, // Needs to be odd!
 }
 
+impl Tylo64 {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 4]) -> Self {
+        let [k, a, b, w] = words;
+        Self {
+            a: Cell::new(a | 1),
+            b: Cell::new(b | 1),
+            w: Cell::new(w | 1),
+            k: Cell::new(if k.is_multiple_of(2) {
+                k.wrapping_sub(1)
+            } else {
+                k
+            }),
+        }
+    }
+}
+
 impl Default for Tylo64 {
     fn default() -> Self {
         let s = Self {
@@ -60,35 +344,57 @@ impl Random for Tylo64 {
     fn u64(&self) -> u64 {
         let b = self.b.get();
 
-        self.w.set(self.w.get() + self.k.get());
+        self.w.set(self.w.get().wrapping_add(self.k.get()));
         let out = self.a.get() ^ self.w.get();
 
-        self.a.set((b + (b << 3)) ^ (b >> 11));
-        self.b.set(((b << 24) | (b >> 40)) + out);
+        self.a.set(b.wrapping_add(b << 3) ^ (b >> 11));
+        self.b.set(b.rotate_left(24).wrapping_add(out));
 
         out
     }
 
     fn seed(&self) {
-        let mut k = get_seed();
-        if k % 2 == 0 {
-            k -= 1;
-        }
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
 
-        self.a.set(get_seed() | 1);
-        self.b.set(get_seed() | 1);
-        self.w.set(get_seed() | 1);
-        self.k.set(k);
+impl SeedableRandom for Tylo64 {
+    const SEED_WORDS: usize = 4;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { a, b, w, k } = Self::from_words(std::array::from_fn(|_| next()));
+        self.a.set(a.into_inner());
+        self.b.set(b.into_inner());
+        self.w.set(w.into_inner());
+        self.k.set(k.into_inner());
     }
 }
 
 /// Copyright 2020 Mark A. Overton
 /// Licensed under Apache-2.0.
+///
+/// This is the generator Mark A. Overton calls RomuDuoJr.
 pub struct RomuJr {
     pub x: Cell<u64>,
     pub y: Cell<u64>,
 }
 
+impl RomuJr {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 2]) -> Self {
+        let [x, y] = words;
+        Self {
+            x: Cell::new(x | 1),
+            y: Cell::new(y | 1),
+        }
+    }
+}
+
 impl Default for RomuJr {
     fn default() -> Self {
         let s = Self {
@@ -105,16 +411,25 @@ impl Random for RomuJr {
     fn u64(&self) -> u64 {
         let xp = self.x.get();
 
-        self.x.set(15241094284759029579 * self.y.get());
-        self.y.set(self.y.get() - xp);
-        self.y.set((self.y.get() << 27) | (self.y.get() >> 37));
+        self.x.set(ROMU_MULTIPLIER.wrapping_mul(self.y.get()));
+        self.y.set(self.y.get().wrapping_sub(xp));
+        self.y.set(self.y.get().rotate_left(27));
 
         xp
     }
 
     fn seed(&self) {
-        self.x.set(get_seed() | 1);
-        self.y.set(get_seed() | 1);
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for RomuJr {
+    const SEED_WORDS: usize = 2;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { x, y } = Self::from_words(std::array::from_fn(|_| next()));
+        self.x.set(x.into_inner());
+        self.y.set(y.into_inner());
     }
 }
 
@@ -126,6 +441,23 @@ pub struct RomuTrio {
     pub z: Cell<u64>,
 }
 
+impl RomuTrio {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 3]) -> Self {
+        let [x, y, z] = words;
+        Self {
+            x: Cell::new(x | 1),
+            y: Cell::new(y | 1),
+            z: Cell::new(z | 1),
+        }
+    }
+}
+
 impl Default for RomuTrio {
     fn default() -> Self {
         let s = Self {
@@ -145,308 +477,2831 @@ impl Random for RomuTrio {
         let yp = self.y.get();
         let zp = self.z.get();
 
-        self.x.set(15241094284759029579 * zp);
-        self.y.set(yp - xp);
-        self.y.set((self.y.get() << 12) | (self.y.get() >> 52));
-        self.z.set(zp - yp);
-        self.z.set((self.z.get() << 44) | (self.z.get() >> 20));
+        self.x.set(ROMU_MULTIPLIER.wrapping_mul(zp));
+        self.y.set(yp.wrapping_sub(xp));
+        self.y.set(self.y.get().rotate_left(12));
+        self.z.set(zp.wrapping_sub(yp));
+        self.z.set(self.z.get().rotate_left(44));
 
         xp
     }
 
     fn seed(&self) {
-        self.x.set(get_seed() | 1);
-        self.y.set(get_seed() | 1);
-        self.z.set(get_seed() | 1);
+        self.seed_from(os_seeds(Self::SEED_WORDS))
     }
 }
 
-/// D. H. Lehmer, Mathematical methods in large-scale computing units.
-/// Proceedings of a Second Symposium on Large Scale Digital Calculating
-/// Machinery;
-/// Annals of the Computation Laboratory, Harvard Univ. 26 (1951), pp. 141-146.
-///
-/// P L'Ecuyer,  Tables of linear congruential generators of different sizes and
-/// good lattice structure. Mathematics of Computation of the American
-/// Mathematical
-/// Society 68.225 (1999): 249-260.
-pub struct Lehmer64 {
-    pub state: Cell<u128>,
+impl SeedableRandom for RomuTrio {
+    const SEED_WORDS: usize = 3;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { x, y, z } = Self::from_words(std::array::from_fn(|_| next()));
+        self.x.set(x.into_inner());
+        self.y.set(y.into_inner());
+        self.z.set(z.into_inner());
+    }
 }
 
-impl Default for Lehmer64 {
+/// Copyright 2020 Mark A. Overton
+/// Licensed under Apache-2.0.
+pub struct RomuQuad {
+    pub w: Cell<u64>,
+    pub x: Cell<u64>,
+    pub y: Cell<u64>,
+    pub z: Cell<u64>,
+}
+
+impl RomuQuad {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 4]) -> Self {
+        let [w, x, y, z] = words;
+        Self {
+            w: Cell::new(w | 1),
+            x: Cell::new(x | 1),
+            y: Cell::new(y | 1),
+            z: Cell::new(z | 1),
+        }
+    }
+}
+
+impl Default for RomuQuad {
     fn default() -> Self {
         let s = Self {
-            state: Cell::new(0),
+            w: Cell::new(0),
+            x: Cell::new(0),
+            y: Cell::new(0),
+            z: Cell::new(0),
         };
         s.seed();
         s
     }
 }
 
-impl Random for Lehmer64 {
+impl Random for RomuQuad {
     #[inline(always)]
     fn u64(&self) -> u64 {
-        let s = self.state.get();
-        self.state
-            .set(self.state.get().wrapping_mul(0xDA942042E4DD58B5));
+        let wp = self.w.get();
+        let xp = self.x.get();
+        let yp = self.y.get();
+        let zp = self.z.get();
 
-        (s >> 64) as u64
+        self.w.set(ROMU_MULTIPLIER.wrapping_mul(zp));
+        self.x.set(zp.wrapping_add(wp.rotate_left(52)));
+        self.y.set(yp.wrapping_sub(xp));
+        self.z.set(yp.wrapping_add(wp));
+        self.z.set(self.z.get().rotate_left(19));
+
+        xp
     }
 
     fn seed(&self) {
-        self.state
-            .set((((get_seed() as u128) << 64) + get_seed() as u128) | 1);
+        self.seed_from(os_seeds(Self::SEED_WORDS))
     }
 }
 
-/// (c) 2021 Tom Kaitchuck
-/// Licensed under MIT or Apache-2.0.
-pub struct Mwc256XXA64 {
-    pub x1: Cell<u64>,
-    pub x2: Cell<u64>,
-    pub x3: Cell<u64>,
-    pub c: Cell<u64>,
+impl SeedableRandom for RomuQuad {
+    const SEED_WORDS: usize = 4;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { w, x, y, z } = Self::from_words(std::array::from_fn(|_| next()));
+        self.w.set(w.into_inner());
+        self.x.set(x.into_inner());
+        self.y.set(y.into_inner());
+        self.z.set(z.into_inner());
+    }
+}
+
+/// Copyright 2020 Mark A. Overton
+/// Licensed under Apache-2.0.
+pub struct RomuDuo {
+    pub x: Cell<u64>,
+    pub y: Cell<u64>,
 }
 
-impl Default for Mwc256XXA64 {
+impl RomuDuo {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 2]) -> Self {
+        let [x, y] = words;
+        Self {
+            x: Cell::new(x | 1),
+            y: Cell::new(y | 1),
+        }
+    }
+}
+
+impl Default for RomuDuo {
     fn default() -> Self {
         let s = Self {
-            x1: Cell::new(0),
-            x2: Cell::new(0),
-            x3: Cell::new(0),
-            c: Cell::new(0),
+            x: Cell::new(0),
+            y: Cell::new(0),
         };
         s.seed();
         s
     }
 }
 
-impl Random for Mwc256XXA64 {
+impl Random for RomuDuo {
     #[inline(always)]
     fn u64(&self) -> u64 {
-        let t = (self.x3.get() as u128).wrapping_mul(0xDEB344657C0AF413);
-        let low = t as u64;
-        let high = (t >> 64) as u64;
-
-        let result = (self.x3.get() ^ self.x2.get()).wrapping_add(self.x1.get() ^ high);
-        let (x1, b) = low.overflowing_add(self.c.get());
+        let xp = self.x.get();
+        let yp = self.y.get();
 
-        self.x3.set(self.x2.get());
-        self.x2.set(self.x1.get());
-        self.x1.set(x1);
-        self.c.set(high.wrapping_add(b as u64));
+        self.x.set(ROMU_MULTIPLIER.wrapping_mul(yp));
+        self.y.set(
+            yp.rotate_left(36)
+                .wrapping_add(yp.rotate_left(15))
+                .wrapping_sub(xp),
+        );
 
-        result
+        xp
     }
 
     fn seed(&self) {
-        self.x1.set(get_seed() | 1);
-        self.x2.set(get_seed() | 1);
-        self.x3.set(get_seed() | 1);
-        self.c.set(get_seed() | 1);
+        self.seed_from(os_seeds(Self::SEED_WORDS))
     }
 }
 
-/// Licensed under "The Unlicense".
-pub struct Wyrand {
-    pub state: Cell<u64>,
+impl SeedableRandom for RomuDuo {
+    const SEED_WORDS: usize = 2;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { x, y } = Self::from_words(std::array::from_fn(|_| next()));
+        self.x.set(x.into_inner());
+        self.y.set(y.into_inner());
+    }
 }
 
-impl Default for Wyrand {
-    fn default() -> Self {
+/// The name Mark A. Overton uses for `RomuJr`.
+pub type RomuDuoJr = RomuJr;
+
+const ROMU_MULTIPLIER: u64 = 15241094284759029579;
+
+/// Chris Doty-Humphrey's Small Fast Counting generator, as found in PractRand.
+///
+/// Public domain.
+pub struct Sfc64 {
+    pub a: Cell<u64>,
+    pub b: Cell<u64>,
+    pub c: Cell<u64>,
+    pub counter: Cell<u64>,
+}
+
+impl Sfc64 {
+    /// Seeds the generator the way PractRand does for a single 64-bit seed.
+    pub const fn new(seed: u64) -> Self {
         let s = Self {
-            state: Cell::new(0),
+            a: Cell::new(seed),
+            b: Cell::new(seed),
+            c: Cell::new(seed),
+            counter: Cell::new(1),
         };
-        s.seed();
+        s.warm_up();
         s
     }
-}
 
-impl Random for Wyrand {
-    #[inline(always)]
-    fn u64(&self) -> u64 {
-        let s = self.state.get();
-        self.state
-            .set(self.state.get().wrapping_add(0xA0761D6478BD642F));
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
 
-        let c = (s ^ 0xE7037ED1A0B428DB) as u128 * s as u128;
-        ((c >> 64) ^ c) as u64
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 3]) -> Self {
+        let [a, b, c] = words;
+        let s = Self {
+            a: Cell::new(a),
+            b: Cell::new(b),
+            c: Cell::new(c),
+            counter: Cell::new(1),
+        };
+        s.warm_up();
+        s
     }
 
-    fn seed(&self) {
-        self.state.set(get_seed() | 1);
+    /// `u64()` as a const function, to build tables at compile time.
+    #[inline(always)]
+    pub const fn next_u64(&self) -> u64 {
+        let a = self.a.get();
+        let b = self.b.get();
+        let c = self.c.get();
+        let counter = self.counter.get();
+
+        let tmp = a.wrapping_add(b).wrapping_add(counter);
+        self.counter.replace(counter.wrapping_add(1));
+        self.a.replace(b ^ (b >> 11));
+        self.b.replace(c.wrapping_add(c << 3));
+        self.c.replace(c.rotate_left(24).wrapping_add(tmp));
+
+        tmp
     }
-}
 
-/// Written in 2015 by Sebastiano Vigna.
-///
-/// To the extent possible under law, the author has dedicated all copyright
-/// and related and neighboring rights to this software to the public domain
-/// worldwide. This software is distributed without any warranty.
-///
-/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
-pub struct Splitmix64 {
-    pub state: Cell<u64>,
+    const fn warm_up(&self) {
+        let mut i = 0;
+        while i < 12 {
+            self.next_u64();
+            i += 1;
+        }
+    }
 }
 
-impl Default for Splitmix64 {
+impl Default for Sfc64 {
     fn default() -> Self {
         let s = Self {
-            state: Cell::new(0),
+            a: Cell::new(0),
+            b: Cell::new(0),
+            c: Cell::new(0),
+            counter: Cell::new(0),
         };
         s.seed();
         s
     }
 }
 
-impl Random for Splitmix64 {
+impl Random for Sfc64 {
     #[inline(always)]
     fn u64(&self) -> u64 {
-        let mut x = self.state.get();
-        self.state.set(self.state.get() + 0x9E3779B97F4A7C15);
-        x = (x ^ (x >> 30)) * 0xBF58476D1CE4E5B9;
-        x = (x ^ (x >> 27)) * 0x94D049BB133111EB;
-        x ^ (x >> 31)
+        self.next_u64()
     }
 
     fn seed(&self) {
-        self.state.set(get_seed() | 1);
+        self.seed_from(os_seeds(Self::SEED_WORDS))
     }
 }
 
-/// Written in 2019 by David Blackman and Sebastiano Vigna.
-///
-/// To the extent possible under law, the author has dedicated all copyright
-/// and related and neighboring rights to this software to the public domain
-/// worldwide. This software is distributed without any warranty.
+impl SeedableRandom for Sfc64 {
+    const SEED_WORDS: usize = 3;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { a, b, c, counter } = Self::from_words(std::array::from_fn(|_| next()));
+        self.a.set(a.into_inner());
+        self.b.set(b.into_inner());
+        self.c.set(c.into_inner());
+        self.counter.set(counter.into_inner());
+    }
+}
+
+/// Bob Jenkins's Small Fast generator, 64-bit variant with the three-rotate
+/// constants.
 ///
-/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
-pub struct Xoshiro256plusplus {
-    pub s0: Cell<u64>,
-    pub s1: Cell<u64>,
-    pub s2: Cell<u64>,
-    pub s3: Cell<u64>,
+/// Public domain.
+pub struct Jsf64 {
+    pub a: Cell<u64>,
+    pub b: Cell<u64>,
+    pub c: Cell<u64>,
+    pub d: Cell<u64>,
 }
 
-impl Default for Xoshiro256plusplus {
-    fn default() -> Self {
+impl Jsf64 {
+    /// Seeds the generator the way `raninit` of the reference implementation does.
+    pub const fn new(seed: u64) -> Self {
         let s = Self {
-            s0: Cell::new(0),
+            a: Cell::new(0),
+            b: Cell::new(0),
+            c: Cell::new(0),
+            d: Cell::new(0),
+        };
+        s.init(seed);
+        s
+    }
+
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 1]) -> Self {
+        Self::new(words[0])
+    }
+
+    /// `u64()` as a const function, to build tables at compile time.
+    #[inline(always)]
+    pub const fn next_u64(&self) -> u64 {
+        let a = self.a.get();
+        let b = self.b.get();
+        let c = self.c.get();
+        let d = self.d.get();
+
+        let e = a.wrapping_sub(b.rotate_left(7));
+        let a = b ^ c.rotate_left(13);
+        self.a.replace(a);
+        self.b.replace(c.wrapping_add(d.rotate_left(37)));
+        self.c.replace(d.wrapping_add(e));
+        self.d.replace(e.wrapping_add(a));
+
+        self.d.get()
+    }
+
+    const fn init(&self, seed: u64) {
+        self.a.replace(0xF1EA5EED);
+        self.b.replace(seed);
+        self.c.replace(seed);
+        self.d.replace(seed);
+
+        let mut i = 0;
+        while i < 20 {
+            self.next_u64();
+            i += 1;
+        }
+    }
+}
+
+impl Default for Jsf64 {
+    fn default() -> Self {
+        Self::new(os_seeds(1)())
+    }
+}
+
+impl Random for Jsf64 {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        self.next_u64()
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Jsf64 {
+    const SEED_WORDS: usize = 1;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { a, b, c, d } = Self::from_words(std::array::from_fn(|_| next()));
+        self.a.set(a.into_inner());
+        self.b.set(b.into_inner());
+        self.c.set(c.into_inner());
+        self.d.set(d.into_inner());
+    }
+}
+
+/// D. H. Lehmer, Mathematical methods in large-scale computing units.
+/// Proceedings of a Second Symposium on Large Scale Digital Calculating
+/// Machinery;
+/// Annals of the Computation Laboratory, Harvard Univ. 26 (1951), pp. 141-146.
+///
+/// P L'Ecuyer,  Tables of linear congruential generators of different sizes and
+/// good lattice structure. Mathematics of Computation of the American
+/// Mathematical
+/// Society 68.225 (1999): 249-260.
+pub struct Lehmer64 {
+    pub state: Cell<u128>,
+}
+
+impl Lehmer64 {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 2]) -> Self {
+        let [high, low] = words;
+        Self {
+            state: Cell::new(words_u128(high, low) | 1),
+        }
+    }
+}
+
+impl Default for Lehmer64 {
+    fn default() -> Self {
+        let s = Self {
+            state: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Lehmer64 {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let s = self.state.get();
+        self.state
+            .set(self.state.get().wrapping_mul(LEHMER64_MULTIPLIER));
+
+        (s >> 64) as u64
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Lehmer64 {
+    const SEED_WORDS: usize = 2;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { state } = Self::from_words(std::array::from_fn(|_| next()));
+        self.state.set(state.into_inner());
+    }
+}
+
+const LEHMER64_MULTIPLIER: u128 = 0xDA942042E4DD58B5;
+const LEHMER64_INVERSE: u128 = inverse_u128(LEHMER64_MULTIPLIER);
+
+impl ReversibleRandom for Lehmer64 {
+    #[inline(always)]
+    fn prev_u64(&self) -> u64 {
+        let s = self.state.get().wrapping_mul(LEHMER64_INVERSE);
+        self.state.set(s);
+
+        (s >> 64) as u64
+    }
+}
+
+macro_rules! mwc_core {
+    ($(#[$meta:meta])* $name:ident, $word:ty, $double:ty) => {
+        $(#[$meta])*
+        pub struct $name<const LAG: usize, const A: $word> {
+            /// The lag words, from the oldest (which gets multiplied) to the newest.
+            pub x: [Cell<$word>; LAG],
+            pub c: Cell<$word>,
+        }
+
+        impl<const LAG: usize, const A: $word> $name<LAG, A> {
+            /// Returns `None` if the carry is out of range or the state is the
+            /// all-zero fixed point.
+            pub fn from_state(x: [$word; LAG], c: $word) -> Option<Self> {
+                let s = Self {
+                    x: x.map(Cell::new),
+                    c: Cell::new(c),
+                };
+                s.is_valid().then_some(s)
+            }
+
+            /// A valid state has a carry `c < A - 1` and is not all zero. All of
+            /// those states lie on the single full period cycle, if `A` is chosen
+            /// properly.
+            pub fn is_valid(&self) -> bool {
+                let c = self.c.get();
+                c < A - 1 && (c != 0 || self.x.iter().any(|x| x.get() != 0))
+            }
+
+            /// Advances the generator and returns the newest lag word.
+            #[inline(always)]
+            pub fn step(&self) -> $word {
+                let t = (A as $double) * (self.x[0].get() as $double) + self.c.get() as $double;
+                let new = t as $word;
+
+                self.x.iter().skip(1).zip(self.x.iter()).for_each(|(next, x)| x.set(next.get()));
+                self.x[LAG - 1].set(new);
+                self.c.set((t >> <$word>::BITS) as $word);
+
+                new
+            }
+
+            /// Consumes `LAG + 1` words.
+            fn seed_from(&self, mut next: impl FnMut() -> u64) {
+                self.set(Self::from_words(std::array::from_fn(|_| next()), next()));
+            }
+
+            /// Takes the lag words from `x` and reduces `c` to a valid carry.
+            pub const fn from_words(x: [u64; LAG], c: u64) -> Self {
+                let cells = [const { Cell::new(0) }; LAG];
+                let mut i = 0;
+                while i < LAG {
+                    cells[i].replace(x[i] as $word);
+                    i += 1;
+                }
+                Self {
+                    x: cells,
+                    c: Cell::new(1 + (c as $word) % (A - 2)),
+                }
+            }
+
+            /// Takes over the state of `other`.
+            fn set(&self, other: Self) {
+                let Self { x, c } = other;
+                self.x.iter().zip(x).for_each(|(s, x)| s.set(x.into_inner()));
+                self.c.set(c.into_inner());
+            }
+        }
+
+        impl<const LAG: usize, const A: $word> Default for $name<LAG, A> {
+            fn default() -> Self {
+                let s = Self {
+                    x: [const { Cell::new(0) }; LAG],
+                    c: Cell::new(0),
+                };
+                s.seed_from(os_seeds(LAG + 1));
+                s
+            }
+        }
+    };
+}
+
+pub(crate) use mwc_core;
+
+mwc_core!(
+    /// Generic Marsaglia multiply-with-carry generator on 64-bit words with lag
+    /// `LAG` and multiplier `A`.
+    ///
+    /// For a full period, `A * 2^(64 * LAG) - 1` needs to be a safe prime.
+    Mwc,
+    u64,
+    u128
+);
+
+mwc_core!(
+    /// Generic Marsaglia multiply-with-carry generator on 32-bit words with lag
+    /// `LAG` and multiplier `A`.
+    ///
+    /// For a full period, `A * 2^(32 * LAG) - 1` needs to be a safe prime.
+    Mwc32,
+    u32,
+    u64
+);
+
+/// Written in 2021 by Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+///
+/// Returns the newest lag word, like the reference implementation.
+#[derive(Default)]
+pub struct Mwc128 {
+    pub mwc: Mwc<1, 0xFFEBB71D94FCDAF9>,
+}
+
+impl Mwc128 {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 2]) -> Self {
+        let [x0, c] = words;
+        Self {
+            mwc: Mwc::from_words([x0], c),
+        }
+    }
+}
+
+impl Random for Mwc128 {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let result = self.mwc.x[0].get();
+        self.mwc.step();
+        result
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Mwc128 {
+    const SEED_WORDS: usize = 2;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { mwc } = Self::from_words(std::array::from_fn(|_| next()));
+        self.mwc.set(mwc);
+    }
+}
+
+/// Written in 2021 by Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+///
+/// Returns the newest lag word, like the reference implementation.
+#[derive(Default)]
+pub struct Mwc192 {
+    pub mwc: Mwc<2, 0xFFA04E67B3C95D86>,
+}
+
+impl Mwc192 {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 3]) -> Self {
+        let [x0, x1, c] = words;
+        Self {
+            mwc: Mwc::from_words([x0, x1], c),
+        }
+    }
+}
+
+impl Random for Mwc192 {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let result = self.mwc.x[1].get();
+        self.mwc.step();
+        result
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Mwc192 {
+    const SEED_WORDS: usize = 3;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { mwc } = Self::from_words(std::array::from_fn(|_| next()));
+        self.mwc.set(mwc);
+    }
+}
+
+/// Written in 2021 by Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+///
+/// Returns the newest lag word, like the reference implementation.
+#[derive(Default)]
+pub struct Mwc256 {
+    pub mwc: Mwc<3, 0xFFF62CF2CCC0CDAF>,
+}
+
+impl Mwc256 {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 4]) -> Self {
+        let [x0, x1, x2, c] = words;
+        Self {
+            mwc: Mwc::from_words([x0, x1, x2], c),
+        }
+    }
+}
+
+impl Random for Mwc256 {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let result = self.mwc.x[2].get();
+        self.mwc.step();
+        result
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Mwc256 {
+    const SEED_WORDS: usize = 4;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { mwc } = Self::from_words(std::array::from_fn(|_| next()));
+        self.mwc.set(mwc);
+    }
+}
+
+/// (c) 2021 Tom Kaitchuck
+/// Licensed under MIT or Apache-2.0.
+#[derive(Default)]
+pub struct Mwc256XXA64 {
+    pub mwc: Mwc<3, 0xFEB344657C0AF413>,
+}
+
+impl Mwc256XXA64 {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 4]) -> Self {
+        let [x0, x1, x2, c] = words;
+        Self {
+            mwc: Mwc::from_words([x0, x1, x2], c),
+        }
+    }
+}
+
+impl Random for Mwc256XXA64 {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let [x3, x2, x1] = self.mwc.x.each_ref().map(Cell::get);
+        let high = ((x3 as u128 * 0xFEB344657C0AF413) >> 64) as u64;
+
+        self.mwc.step();
+
+        (x3 ^ x2).wrapping_add(x1 ^ high)
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Mwc256XXA64 {
+    const SEED_WORDS: usize = 4;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { mwc } = Self::from_words(std::array::from_fn(|_| next()));
+        self.mwc.set(mwc);
+    }
+}
+
+/// (c) 2021 Tom Kaitchuck
+/// Licensed under MIT or Apache-2.0.
+#[derive(Default)]
+pub struct Mwc128XXA32 {
+    pub mwc: Mwc32<3, 0xCFDBC53D>,
+}
+
+impl Mwc128XXA32 {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 4]) -> Self {
+        let [x0, x1, x2, c] = words;
+        Self {
+            mwc: Mwc32::from_words([x0, x1, x2], c),
+        }
+    }
+}
+
+impl Random32 for Mwc128XXA32 {
+    #[inline(always)]
+    fn u32(&self) -> u32 {
+        let [x3, x2, x1] = self.mwc.x.each_ref().map(Cell::get);
+        let high = ((x3 as u64 * 0xCFDBC53D) >> 32) as u32;
+
+        self.mwc.step();
+
+        (x3 ^ x2).wrapping_add(x1 ^ high)
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom32 for Mwc128XXA32 {
+    const SEED_WORDS: usize = 4;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { mwc } = Self::from_words(std::array::from_fn(|_| next()));
+        self.mwc.set(mwc);
+    }
+}
+
+/// Licensed under "The Unlicense".
+pub struct Wyrand {
+    pub state: Cell<u64>,
+}
+
+impl Wyrand {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 1]) -> Self {
+        Self {
+            state: Cell::new(words[0] | 1),
+        }
+    }
+
+    /// `u64()` as a const function, to build tables at compile time.
+    #[inline(always)]
+    pub const fn next_u64(&self) -> u64 {
+        let s = self.state.get();
+        self.state.replace(s.wrapping_add(WYRAND_INCREMENT));
+        wyrand_mix(s)
+    }
+}
+
+impl Default for Wyrand {
+    fn default() -> Self {
+        let s = Self {
+            state: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Wyrand {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        self.next_u64()
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Wyrand {
+    const SEED_WORDS: usize = 1;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { state } = Self::from_words(std::array::from_fn(|_| next()));
+        self.state.set(state.into_inner());
+    }
+}
+
+const WYRAND_INCREMENT: u64 = 0xA0761D6478BD642F;
+
+#[inline(always)]
+const fn wyrand_mix(s: u64) -> u64 {
+    let c = (s ^ 0xE7037ED1A0B428DB) as u128 * s as u128;
+    ((c >> 64) ^ c) as u64
+}
+
+/// Written in 2015 by Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+pub struct Splitmix64 {
+    pub state: Cell<u64>,
+}
+
+impl Splitmix64 {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 1]) -> Self {
+        Self {
+            state: Cell::new(words[0] | 1),
+        }
+    }
+
+    /// `u64()` as a const function, to build tables at compile time.
+    #[inline(always)]
+    pub const fn next_u64(&self) -> u64 {
+        let x = self.state.get();
+        self.state.replace(x.wrapping_add(SPLITMIX64_GAMMA));
+        splitmix64_mix(x)
+    }
+}
+
+impl Default for Splitmix64 {
+    fn default() -> Self {
+        let s = Self {
+            state: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Splitmix64 {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        self.next_u64()
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Splitmix64 {
+    const SEED_WORDS: usize = 1;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { state } = Self::from_words(std::array::from_fn(|_| next()));
+        self.state.set(state.into_inner());
+    }
+}
+
+impl ReversibleRandom for Splitmix64 {
+    #[inline(always)]
+    fn prev_u64(&self) -> u64 {
+        let x = self.state.get().wrapping_sub(SPLITMIX64_GAMMA);
+        self.state.set(x);
+        splitmix64_mix(x)
+    }
+}
+
+const SPLITMIX64_GAMMA: u64 = 0x9E3779B97F4A7C15;
+
+#[inline(always)]
+const fn splitmix64_mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D049BB133111EB);
+    x ^ (x >> 31)
+}
+
+/// Written in 2019 by David Blackman and Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+pub struct Xoshiro256plusplus {
+    pub s0: Cell<u64>,
+    pub s1: Cell<u64>,
+    pub s2: Cell<u64>,
+    pub s3: Cell<u64>,
+}
+
+impl Xoshiro256plusplus {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 4]) -> Self {
+        let [s0, s1, s2, s3] = words;
+        Self {
+            s0: Cell::new(s0 | 1),
+            s1: Cell::new(s1 | 1),
+            s2: Cell::new(s2 | 1),
+            s3: Cell::new(s3 | 1),
+        }
+    }
+
+    /// Equivalent to 2^128 calls to `u64()`. Generates 2^128 non-overlapping subsequences.
+    pub fn jump(&self) {
+        xoshiro_jump(
+            self,
+            [&self.s0, &self.s1, &self.s2, &self.s3],
+            &XOSHIRO256_JUMP,
+        );
+    }
+
+    /// Equivalent to 2^192 calls to `u64()`. Generates 2^64 starting points for `jump()`.
+    pub fn long_jump(&self) {
+        xoshiro_jump(
+            self,
+            [&self.s0, &self.s1, &self.s2, &self.s3],
+            &XOSHIRO256_LONG_JUMP,
+        );
+    }
+}
+
+impl Default for Xoshiro256plusplus {
+    fn default() -> Self {
+        let s = Self {
+            s0: Cell::new(0),
+            s1: Cell::new(0),
+            s2: Cell::new(0),
+            s3: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Xoshiro256plusplus {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let result = (self.s0.get().wrapping_add(self.s3.get()))
+            .rotate_left(23)
+            .wrapping_add(self.s0.get());
+
+        xoshiro256_step(&self.s0, &self.s1, &self.s2, &self.s3);
+
+        result
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Xoshiro256plusplus {
+    const SEED_WORDS: usize = 4;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { s0, s1, s2, s3 } = Self::from_words(std::array::from_fn(|_| next()));
+        self.s0.set(s0.into_inner());
+        self.s1.set(s1.into_inner());
+        self.s2.set(s2.into_inner());
+        self.s3.set(s3.into_inner());
+    }
+}
+
+impl ReversibleRandom for Xoshiro256plusplus {
+    #[inline(always)]
+    fn prev_u64(&self) -> u64 {
+        xoshiro256_step_back(&self.s0, &self.s1, &self.s2, &self.s3);
+
+        (self.s0.get().wrapping_add(self.s3.get()))
+            .rotate_left(23)
+            .wrapping_add(self.s0.get())
+    }
+}
+
+/// Written in 2018 by David Blackman and Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+pub struct Xoshiro256starstar {
+    pub s0: Cell<u64>,
+    pub s1: Cell<u64>,
+    pub s2: Cell<u64>,
+    pub s3: Cell<u64>,
+}
+
+impl Xoshiro256starstar {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 4]) -> Self {
+        let [s0, s1, s2, s3] = words;
+        Self {
+            s0: Cell::new(s0 | 1),
+            s1: Cell::new(s1 | 1),
+            s2: Cell::new(s2 | 1),
+            s3: Cell::new(s3 | 1),
+        }
+    }
+
+    /// Equivalent to 2^128 calls to `u64()`. Generates 2^128 non-overlapping subsequences.
+    pub fn jump(&self) {
+        xoshiro_jump(
+            self,
+            [&self.s0, &self.s1, &self.s2, &self.s3],
+            &XOSHIRO256_JUMP,
+        );
+    }
+
+    /// Equivalent to 2^192 calls to `u64()`. Generates 2^64 starting points for `jump()`.
+    pub fn long_jump(&self) {
+        xoshiro_jump(
+            self,
+            [&self.s0, &self.s1, &self.s2, &self.s3],
+            &XOSHIRO256_LONG_JUMP,
+        );
+    }
+}
+
+impl Default for Xoshiro256starstar {
+    fn default() -> Self {
+        let s = Self {
+            s0: Cell::new(0),
+            s1: Cell::new(0),
+            s2: Cell::new(0),
+            s3: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Xoshiro256starstar {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let result = self.s1.get().wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+
+        xoshiro256_step(&self.s0, &self.s1, &self.s2, &self.s3);
+
+        result
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Xoshiro256starstar {
+    const SEED_WORDS: usize = 4;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { s0, s1, s2, s3 } = Self::from_words(std::array::from_fn(|_| next()));
+        self.s0.set(s0.into_inner());
+        self.s1.set(s1.into_inner());
+        self.s2.set(s2.into_inner());
+        self.s3.set(s3.into_inner());
+    }
+}
+
+/// Written in 2018 by David Blackman and Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+///
+/// The lowest three bits have low linear complexity. Use the upper bits for
+/// floating point numbers.
+pub struct Xoshiro256plus {
+    pub s0: Cell<u64>,
+    pub s1: Cell<u64>,
+    pub s2: Cell<u64>,
+    pub s3: Cell<u64>,
+}
+
+impl Xoshiro256plus {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 4]) -> Self {
+        let [s0, s1, s2, s3] = words;
+        Self {
+            s0: Cell::new(s0 | 1),
+            s1: Cell::new(s1 | 1),
+            s2: Cell::new(s2 | 1),
+            s3: Cell::new(s3 | 1),
+        }
+    }
+
+    /// Equivalent to 2^128 calls to `u64()`. Generates 2^128 non-overlapping subsequences.
+    pub fn jump(&self) {
+        xoshiro_jump(
+            self,
+            [&self.s0, &self.s1, &self.s2, &self.s3],
+            &XOSHIRO256_JUMP,
+        );
+    }
+
+    /// Equivalent to 2^192 calls to `u64()`. Generates 2^64 starting points for `jump()`.
+    pub fn long_jump(&self) {
+        xoshiro_jump(
+            self,
+            [&self.s0, &self.s1, &self.s2, &self.s3],
+            &XOSHIRO256_LONG_JUMP,
+        );
+    }
+}
+
+impl Default for Xoshiro256plus {
+    fn default() -> Self {
+        let s = Self {
+            s0: Cell::new(0),
             s1: Cell::new(0),
             s2: Cell::new(0),
             s3: Cell::new(0),
         };
-        s.seed();
+        s.seed();
+        s
+    }
+}
+
+impl Random for Xoshiro256plus {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let result = self.s0.get().wrapping_add(self.s3.get());
+
+        xoshiro256_step(&self.s0, &self.s1, &self.s2, &self.s3);
+
+        result
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Xoshiro256plus {
+    const SEED_WORDS: usize = 4;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { s0, s1, s2, s3 } = Self::from_words(std::array::from_fn(|_| next()));
+        self.s0.set(s0.into_inner());
+        self.s1.set(s1.into_inner());
+        self.s2.set(s2.into_inner());
+        self.s3.set(s3.into_inner());
+    }
+}
+
+/// Written in 2019 by David Blackman and Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+pub struct Xoshiro512plusplus {
+    pub s: [Cell<u64>; 8],
+}
+
+impl Xoshiro512plusplus {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 8]) -> Self {
+        let s = [const { Cell::new(0) }; 8];
+        let mut i = 0;
+        while i < s.len() {
+            s[i].replace(words[i] | 1);
+            i += 1;
+        }
+        Self { s }
+    }
+
+    /// Equivalent to 2^256 calls to `u64()`. Generates 2^256 non-overlapping subsequences.
+    pub fn jump(&self) {
+        xoshiro_jump(self, self.s.each_ref(), &XOSHIRO512_JUMP);
+    }
+
+    /// Equivalent to 2^384 calls to `u64()`. Generates 2^128 starting points for `jump()`.
+    pub fn long_jump(&self) {
+        xoshiro_jump(self, self.s.each_ref(), &XOSHIRO512_LONG_JUMP);
+    }
+}
+
+impl Default for Xoshiro512plusplus {
+    fn default() -> Self {
+        let s = Self {
+            s: Default::default(),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Xoshiro512plusplus {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let s = &self.s;
+        let result = (s[0].get().wrapping_add(s[2].get()))
+            .rotate_left(17)
+            .wrapping_add(s[2].get());
+
+        let t = s[1].get() << 11;
+
+        s[2].set(s[2].get() ^ s[0].get());
+        s[5].set(s[5].get() ^ s[1].get());
+        s[1].set(s[1].get() ^ s[2].get());
+        s[7].set(s[7].get() ^ s[3].get());
+        s[3].set(s[3].get() ^ s[4].get());
+        s[4].set(s[4].get() ^ s[5].get());
+        s[0].set(s[0].get() ^ s[6].get());
+        s[6].set(s[6].get() ^ s[7].get());
+
+        s[6].set(s[6].get() ^ t);
+
+        s[7].set(s[7].get().rotate_left(21));
+
+        result
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Xoshiro512plusplus {
+    const SEED_WORDS: usize = 8;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { s } = Self::from_words(std::array::from_fn(|_| next()));
+        self.s
+            .iter()
+            .zip(s)
+            .for_each(|(x, s)| x.set(s.into_inner()));
+    }
+}
+
+/// Written in 2019 by David Blackman and Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+pub struct Xoroshiro128plusplus {
+    pub s0: Cell<u64>,
+    pub s1: Cell<u64>,
+}
+
+impl Xoroshiro128plusplus {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 2]) -> Self {
+        let [s0, s1] = words;
+        Self {
+            s0: Cell::new(s0 | 1),
+            s1: Cell::new(s1 | 1),
+        }
+    }
+
+    /// Equivalent to 2^64 calls to `u64()`. Generates 2^64 non-overlapping subsequences.
+    pub fn jump(&self) {
+        xoshiro_jump(self, [&self.s0, &self.s1], &XOROSHIRO128PLUSPLUS_JUMP);
+    }
+
+    /// Equivalent to 2^96 calls to `u64()`. Generates 2^32 starting points for `jump()`.
+    pub fn long_jump(&self) {
+        xoshiro_jump(self, [&self.s0, &self.s1], &XOROSHIRO128PLUSPLUS_LONG_JUMP);
+    }
+}
+
+impl Default for Xoroshiro128plusplus {
+    fn default() -> Self {
+        let s = Self {
+            s0: Cell::new(0),
+            s1: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Xoroshiro128plusplus {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let s0 = self.s0.get();
+        let mut s1 = self.s1.get();
+        let result = (s0.wrapping_add(s1)).rotate_left(17).wrapping_add(s0);
+
+        s1 ^= s0;
+        self.s0.set(s0.rotate_left(49) ^ s1 ^ (s1 << 21));
+        self.s1.set(s1.rotate_left(28));
+
+        result
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Xoroshiro128plusplus {
+    const SEED_WORDS: usize = 2;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { s0, s1 } = Self::from_words(std::array::from_fn(|_| next()));
+        self.s0.set(s0.into_inner());
+        self.s1.set(s1.into_inner());
+    }
+}
+
+/// Written in 2018 by David Blackman and Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+pub struct Xoroshiro128starstar {
+    pub s0: Cell<u64>,
+    pub s1: Cell<u64>,
+}
+
+impl Xoroshiro128starstar {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 2]) -> Self {
+        let [s0, s1] = words;
+        Self {
+            s0: Cell::new(s0 | 1),
+            s1: Cell::new(s1 | 1),
+        }
+    }
+
+    /// Equivalent to 2^64 calls to `u64()`. Generates 2^64 non-overlapping subsequences.
+    pub fn jump(&self) {
+        xoshiro_jump(self, [&self.s0, &self.s1], &XOROSHIRO128_JUMP);
+    }
+
+    /// Equivalent to 2^96 calls to `u64()`. Generates 2^32 starting points for `jump()`.
+    pub fn long_jump(&self) {
+        xoshiro_jump(self, [&self.s0, &self.s1], &XOROSHIRO128_LONG_JUMP);
+    }
+}
+
+impl Default for Xoroshiro128starstar {
+    fn default() -> Self {
+        let s = Self {
+            s0: Cell::new(0),
+            s1: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Xoroshiro128starstar {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let result = self.s0.get().wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+
+        xoroshiro128_step(&self.s0, &self.s1);
+
+        result
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Xoroshiro128starstar {
+    const SEED_WORDS: usize = 2;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { s0, s1 } = Self::from_words(std::array::from_fn(|_| next()));
+        self.s0.set(s0.into_inner());
+        self.s1.set(s1.into_inner());
+    }
+}
+
+/// Written in 2016-2018 by David Blackman and Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+///
+/// The lowest bit has low linear complexity. Use the upper bits for floating
+/// point numbers.
+pub struct Xoroshiro128plus {
+    pub s0: Cell<u64>,
+    pub s1: Cell<u64>,
+}
+
+impl Xoroshiro128plus {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 2]) -> Self {
+        let [s0, s1] = words;
+        Self {
+            s0: Cell::new(s0 | 1),
+            s1: Cell::new(s1 | 1),
+        }
+    }
+
+    /// Equivalent to 2^64 calls to `u64()`. Generates 2^64 non-overlapping subsequences.
+    pub fn jump(&self) {
+        xoshiro_jump(self, [&self.s0, &self.s1], &XOROSHIRO128_JUMP);
+    }
+
+    /// Equivalent to 2^96 calls to `u64()`. Generates 2^32 starting points for `jump()`.
+    pub fn long_jump(&self) {
+        xoshiro_jump(self, [&self.s0, &self.s1], &XOROSHIRO128_LONG_JUMP);
+    }
+}
+
+impl Default for Xoroshiro128plus {
+    fn default() -> Self {
+        let s = Self {
+            s0: Cell::new(0),
+            s1: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Xoroshiro128plus {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let result = self.s0.get().wrapping_add(self.s1.get());
+
+        xoroshiro128_step(&self.s0, &self.s1);
+
+        result
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Xoroshiro128plus {
+    const SEED_WORDS: usize = 2;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { s0, s1 } = Self::from_words(std::array::from_fn(|_| next()));
+        self.s0.set(s0.into_inner());
+        self.s1.set(s1.into_inner());
+    }
+}
+
+/// Written in 2019 by David Blackman and Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+pub struct Xoroshiro1024plusplus {
+    pub s: [Cell<u64>; 16],
+    pub p: Cell<usize>, // Needs to be smaller than 16!
+}
+
+impl Xoroshiro1024plusplus {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 16]) -> Self {
+        let s = [const { Cell::new(0) }; 16];
+        let mut i = 0;
+        while i < s.len() {
+            s[i].replace(words[i] | 1);
+            i += 1;
+        }
+        Self { s, p: Cell::new(0) }
+    }
+
+    /// Equivalent to 2^512 calls to `u64()`. Generates 2^512 non-overlapping subsequences.
+    pub fn jump(&self) {
+        self.jump_with(&[
+            0x931197D8E3177F17,
+            0xB59422E0B9138C5F,
+            0xF06A6AFB49D668BB,
+            0xACB8A6412C8A1401,
+            0x12304EC85F0B3468,
+            0xB7DFE7079209891E,
+            0x405B7EEC77D9EB14,
+            0x34EAD68280C44E4A,
+            0xE0E4BA3E0AC9E366,
+            0x8F46EDA8348905B7,
+            0x328BF4DBAD90D6FF,
+            0xC8FD6FB31C9EFFC3,
+            0xE899D452D4B67652,
+            0x45F387286ADE3205,
+            0x03864F454A8920BD,
+            0xA68FA28725B1B384,
+        ]);
+    }
+
+    /// Equivalent to 2^768 calls to `u64()`. Generates 2^256 starting points for `jump()`.
+    pub fn long_jump(&self) {
+        self.jump_with(&[
+            0x7374156360BBF00F,
+            0x4630C2EFA3B3C1F6,
+            0x6654183A892786B1,
+            0x94F7BFCBFB0F1661,
+            0x27D8243D3D13EB2D,
+            0x9701730F3DFB300F,
+            0x2F293BAAE6F604AD,
+            0xA661831CB60CD8B6,
+            0x68280C77D9FE008C,
+            0x50554160F5BA9459,
+            0x2FC20B17EC7B2A9A,
+            0x49189BBDC8EC9F8F,
+            0x92A65BCA41852CC1,
+            0xF46820DD0509C12A,
+            0x52B00C35FBF92185,
+            0x1E5B3B7F589E03C1,
+        ]);
+    }
+
+    fn jump_with(&self, polynomial: &[u64; 16]) {
+        // The state is a ring buffer starting at `p`, so it is rotated on the fly.
+        let mut t = [0u64; 16];
+        for j in polynomial {
+            for b in 0..64 {
+                if (j & 1 << b) != 0 {
+                    let p = self.p.get();
+                    t.iter_mut()
+                        .enumerate()
+                        .for_each(|(i, t)| *t ^= self.s[(i + p) & 15].get());
+                }
+                self.u64();
+            }
+        }
+
+        let p = self.p.get();
+        t.iter()
+            .enumerate()
+            .for_each(|(i, t)| self.s[(i + p) & 15].set(*t));
+    }
+}
+
+impl Default for Xoroshiro1024plusplus {
+    fn default() -> Self {
+        let s = Self {
+            s: Default::default(),
+            p: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Xoroshiro1024plusplus {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let q = self.p.get();
+        let p = (q + 1) & 15;
+        self.p.set(p);
+
+        let s0 = self.s[p].get();
+        let mut s15 = self.s[q].get();
+        let result = (s0.wrapping_add(s15)).rotate_left(23).wrapping_add(s15);
+
+        s15 ^= s0;
+        self.s[q].set(s0.rotate_left(25) ^ s15 ^ (s15 << 27));
+        self.s[p].set(s15.rotate_left(36));
+
+        result
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Xoroshiro1024plusplus {
+    const SEED_WORDS: usize = 16;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { s, p } = Self::from_words(std::array::from_fn(|_| next()));
+        self.s
+            .iter()
+            .zip(s)
+            .for_each(|(x, s)| x.set(s.into_inner()));
+        self.p.set(p.into_inner());
+    }
+}
+
+const XOSHIRO256_JUMP: [u64; 4] = [
+    0x180EC6D33CFD0ABA,
+    0xD5A61266F0C9392C,
+    0xA9582618E03FC9AA,
+    0x39ABDC4529B1661C,
+];
+
+const XOSHIRO256_LONG_JUMP: [u64; 4] = [
+    0x76E15D3EFEFDCBBF,
+    0xC5004E441C522FB3,
+    0x77710069854EE241,
+    0x39109BB02ACBE635,
+];
+
+const XOSHIRO512_JUMP: [u64; 8] = [
+    0x33ED89B6E7A353F9,
+    0x760083D7955323BE,
+    0x2837F2FBB5F22FAE,
+    0x4B8C5674D309511C,
+    0xB11AC47A7BA28C25,
+    0xF1BE7667092BCC1C,
+    0x53851EFDB6DF0AAF,
+    0x1EBBC8B23EAF25DB,
+];
+
+const XOSHIRO512_LONG_JUMP: [u64; 8] = [
+    0x11467FEF8F921D28,
+    0xA2A819F2E79C8EA8,
+    0xA8299FC284B3959A,
+    0xB4D347340CA63EE1,
+    0x1CB0940BEDBFF6CE,
+    0xD956C5C4FA1F8E17,
+    0x915E38FD4EDA93BC,
+    0x5B3CCDFA5D7DACA5,
+];
+
+const XOROSHIRO128_JUMP: [u64; 2] = [0xDF900294D8F554A5, 0x170865DF4B3201FC];
+
+const XOROSHIRO128_LONG_JUMP: [u64; 2] = [0xD2A98B26625EEE7B, 0xDDDF9B1090AA7AC1];
+
+const XOROSHIRO128PLUSPLUS_JUMP: [u64; 2] = [0x2BD7A6A6E99C2DDC, 0x0992CCAF6A6FCA05];
+
+const XOROSHIRO128PLUSPLUS_LONG_JUMP: [u64; 2] = [0x360FD5F2CF8D5D99, 0x9C6E6877736C46E3];
+
+#[inline(always)]
+fn xoshiro256_step(s0: &Cell<u64>, s1: &Cell<u64>, s2: &Cell<u64>, s3: &Cell<u64>) {
+    let t = s1.get() << 17;
+
+    s2.set(s2.get() ^ s0.get());
+    s3.set(s3.get() ^ s1.get());
+    s1.set(s1.get() ^ s2.get());
+    s0.set(s0.get() ^ s3.get());
+
+    s2.set(s2.get() ^ t);
+
+    s3.set(s3.get().rotate_left(45));
+}
+
+/// Inverts `xoshiro256_step()`.
+#[inline(always)]
+fn xoshiro256_step_back(s0: &Cell<u64>, s1: &Cell<u64>, s2: &Cell<u64>, s3: &Cell<u64>) {
+    // Undoes the rotation, then s0 ^= s3 with the updated s3.
+    s3.set(s3.get().rotate_right(45));
+    s0.set(s0.get() ^ s3.get());
+
+    // The new s1 is s1 ^ s2 ^ s0 and the new s2 is s2 ^ s0 ^ (s1 << 17), so
+    // their xor is s1 ^ (s1 << 17), which unrolls to the old s1.
+    let mut x = s1.get() ^ s2.get();
+    x ^= x << 17;
+    x ^= x << 34;
+    s1.set(x);
+
+    s2.set(s2.get() ^ (s1.get() << 17) ^ s0.get());
+    s3.set(s3.get() ^ s1.get());
+}
+
+#[inline(always)]
+fn xoroshiro128_step(s0: &Cell<u64>, s1: &Cell<u64>) {
+    let x = s0.get();
+    let y = s1.get() ^ x;
+
+    s0.set(x.rotate_left(24) ^ y ^ (y << 16));
+    s1.set(y.rotate_left(37));
+}
+
+/// Applies a jump polynomial to the linear engine of a xoshiro / xoroshiro generator.
+fn xoshiro_jump<R: Random, const N: usize>(rng: &R, state: [&Cell<u64>; N], polynomial: &[u64; N]) {
+    let mut t = [0u64; N];
+    for j in polynomial {
+        for b in 0..64 {
+            if (j & 1 << b) != 0 {
+                t.iter_mut()
+                    .zip(state.iter())
+                    .for_each(|(t, s)| *t ^= s.get());
+            }
+            rng.u64();
+        }
+    }
+
+    state.iter().zip(t).for_each(|(s, t)| s.set(t));
+}
+
+/// Copyright 2014-2019 Melissa O'Neill and the PCG Project contributors.
+/// Licensed under MIT or Apache-2.0.
+pub struct Pcg64 {
+    pub state: Cell<u128>,
+    pub inc: Cell<u128>,
+}
+
+impl Pcg64 {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 4]) -> Self {
+        let [state_high, state_low, inc_high, inc_low] = words;
+        Self {
+            state: Cell::new(words_u128(state_high, state_low) | 1),
+            inc: Cell::new(words_u128(inc_high, inc_low) | 1),
+        }
+    }
+}
+
+impl Default for Pcg64 {
+    fn default() -> Self {
+        let s = Self {
+            state: Cell::new(0),
+            inc: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Pcg64 {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let s = self.state.get();
+        self.state.set(
+            self.state
+                .get()
+                .wrapping_mul(PCG_DEFAULT_MULTIPLIER_128)
+                .wrapping_add(self.inc.get()),
+        );
+
+        pcg_output_xsl_rr(s)
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Pcg64 {
+    const SEED_WORDS: usize = 4;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { state, inc } = Self::from_words(std::array::from_fn(|_| next()));
+        self.state.set(state.into_inner());
+        self.inc.set(inc.into_inner());
+    }
+}
+
+impl ReversibleRandom for Pcg64 {
+    #[inline(always)]
+    fn prev_u64(&self) -> u64 {
+        let s = self
+            .state
+            .get()
+            .wrapping_sub(self.inc.get())
+            .wrapping_mul(PCG_DEFAULT_INVERSE_128);
+        self.state.set(s);
+
+        pcg_output_xsl_rr(s)
+    }
+}
+
+/// Copyright 2014-2019 Melissa O'Neill and the PCG Project contributors.
+/// Licensed under MIT or Apache-2.0.
+pub struct Pcg64Fast {
+    pub state: Cell<u128>,
+}
+
+impl Pcg64Fast {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 2]) -> Self {
+        let [high, low] = words;
+        Self {
+            state: Cell::new(words_u128(high, low) | 1),
+        }
+    }
+}
+
+impl Default for Pcg64Fast {
+    fn default() -> Self {
+        let s = Self {
+            state: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Pcg64Fast {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let s = self.state.get();
+        self.state
+            .set(self.state.get().wrapping_mul(PCG_DEFAULT_MULTIPLIER_128));
+
+        pcg_output_xsl_rr(s)
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Pcg64Fast {
+    const SEED_WORDS: usize = 2;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { state } = Self::from_words(std::array::from_fn(|_| next()));
+        self.state.set(state.into_inner());
+    }
+}
+
+impl ReversibleRandom for Pcg64Fast {
+    #[inline(always)]
+    fn prev_u64(&self) -> u64 {
+        let s = self.state.get().wrapping_mul(PCG_DEFAULT_INVERSE_128);
+        self.state.set(s);
+
+        pcg_output_xsl_rr(s)
+    }
+}
+
+const PCG_DEFAULT_MULTIPLIER_128: u128 = 0x2360ED051FC65DA44385DF649FCCF645;
+const PCG_DEFAULT_INVERSE_128: u128 = inverse_u128(PCG_DEFAULT_MULTIPLIER_128);
+
+#[inline(always)]
+fn pcg_output_xsl_rr(state: u128) -> u64 {
+    let rot = (state >> 122) as u32;
+    let xsl = ((state >> 64) as u64) ^ (state as u64);
+    xsl.rotate_right(rot)
+}
+
+/// Copyright 2014-2019 Melissa O'Neill and the PCG Project contributors.
+/// Licensed under MIT or Apache-2.0.
+///
+/// The "cheap multiplier" DXSM variant that NumPy uses as `PCG64DXSM`.
+pub struct Pcg64Dxsm {
+    pub state: Cell<u128>,
+    pub inc: Cell<u128>, // Needs to be odd!
+}
+
+impl Pcg64Dxsm {
+    /// Seeds the generator like `pcg_cm_srandom_r` of NumPy and pcg-cpp, so the
+    /// resulting stream matches theirs for the same `initstate` and `initseq`.
+    pub fn new(initstate: u128, initseq: u128) -> Self {
+        let inc = (initseq << 1) | 1;
+        let s = Self {
+            state: Cell::new(initstate.wrapping_add(inc)),
+            inc: Cell::new(inc),
+        };
+        s.step();
+        s
+    }
+
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 4]) -> Self {
+        let [state_high, state_low, inc_high, inc_low] = words;
+        Self {
+            state: Cell::new(words_u128(state_high, state_low)),
+            inc: Cell::new(words_u128(inc_high, inc_low) | 1),
+        }
+    }
+
+    #[inline(always)]
+    fn step(&self) {
+        self.state.set(
+            self.state
+                .get()
+                .wrapping_mul(PCG_CHEAP_MULTIPLIER as u128)
+                .wrapping_add(self.inc.get()),
+        );
+    }
+}
+
+impl Default for Pcg64Dxsm {
+    fn default() -> Self {
+        let s = Self {
+            state: Cell::new(0),
+            inc: Cell::new(1),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Pcg64Dxsm {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let s = self.state.get();
+        self.step();
+        pcg_output_dxsm(s)
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Pcg64Dxsm {
+    const SEED_WORDS: usize = 4;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { state, inc } = Self::from_words(std::array::from_fn(|_| next()));
+        self.state.set(state.into_inner());
+        self.inc.set(inc.into_inner());
+    }
+}
+
+/// Copyright 2014-2019 Melissa O'Neill and the PCG Project contributors.
+/// Licensed under MIT or Apache-2.0.
+///
+/// The MCG counterpart of `Pcg64Dxsm`, using the 64-bit "cheap multiplier".
+pub struct Pcg64DxsmFast {
+    pub state: Cell<u128>, // Needs to be odd!
+}
+
+impl Pcg64DxsmFast {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 2]) -> Self {
+        let [high, low] = words;
+        Self {
+            state: Cell::new(words_u128(high, low) | 1),
+        }
+    }
+}
+
+impl Default for Pcg64DxsmFast {
+    fn default() -> Self {
+        let s = Self {
+            state: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Pcg64DxsmFast {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let s = self.state.get();
+        self.state
+            .set(self.state.get().wrapping_mul(PCG_CHEAP_MULTIPLIER as u128));
+
+        pcg_output_dxsm(s)
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Pcg64DxsmFast {
+    const SEED_WORDS: usize = 2;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { state } = Self::from_words(std::array::from_fn(|_| next()));
+        self.state.set(state.into_inner());
+    }
+}
+
+const PCG_CHEAP_MULTIPLIER: u64 = 0xDA942042E4DD58B5;
+
+#[inline(always)]
+fn pcg_output_dxsm(state: u128) -> u64 {
+    let mut hi = (state >> 64) as u64;
+    let lo = (state as u64) | 1;
+
+    hi ^= hi >> 32;
+    hi = hi.wrapping_mul(PCG_CHEAP_MULTIPLIER);
+    hi ^= hi >> 48;
+    hi.wrapping_mul(lo)
+}
+
+/// Marker for generators that are cryptographically secure, once seeded by
+/// `Default` or `seed()`.
+///
+/// APIs that need unpredictable output should bound on this trait, so that a
+/// fast non-cryptographic generator can't be passed by mistake:
+///
+/// ```compile_fail
+/// use prng_benchmark::{CryptoRandom, Wyrand};
+///
+/// fn session_token(rng: &impl CryptoRandom) -> u64 {
+///     rng.u64()
+/// }
+///
+/// session_token(&Wyrand::default());
+/// ```
+pub trait CryptoRandom: Random {}
+
+/// ChaCha by Daniel J. Bernstein, using the original layout with a 64-bit
+/// block counter (words 12 and 13) and a 64-bit nonce (words 14 and 15).
+///
+/// Portable implementation of the block function without any SIMD.
+///
+/// `ROUNDS` has to be even and at least 8. Only `ChaCha8`, `ChaCha12` and
+/// `ChaCha20` are `CryptoRandom`.
+///
+/// ```compile_fail
+/// use prng_benchmark::ChaCha;
+///
+/// let rng = ChaCha::<7>::from_seed(0);
+/// ```
+pub struct ChaCha<const ROUNDS: usize> {
+    pub state: [Cell<u32>; 16],
+    pub buffer: [Cell<u32>; 16],
+    pub index: Cell<usize>, // Index of the next unused word in the buffer. Odd values are rounded down.
+}
+
+pub type ChaCha8 = ChaCha<8>;
+pub type ChaCha12 = ChaCha<12>;
+pub type ChaCha20 = ChaCha<20>;
+
+impl<const ROUNDS: usize> ChaCha<ROUNDS> {
+    const VALID_ROUNDS: () = assert!(
+        ROUNDS >= 8 && ROUNDS.is_multiple_of(2),
+        "ChaCha needs an even number of at least 8 rounds"
+    );
+
+    pub const fn new(key: [u8; 32], nonce: u64) -> Self {
+        let s = Self::with_key(key);
+        s.state[14].replace(nonce as u32);
+        s.state[15].replace((nonce >> 32) as u32);
+        s
+    }
+
+    /// Uses the IETF layout of RFC 8439 with a 32-bit block counter and a 96-bit nonce.
+    ///
+    /// The block counter carries into the first nonce word after 256 GiB of output,
+    /// which is more than RFC 8439 allows to be generated for a single nonce anyway.
+    pub const fn from_rfc8439(key: [u8; 32], counter: u32, nonce: [u8; 12]) -> Self {
+        let s = Self::with_key(key);
+        s.state[12].replace(counter);
+        let mut i = 0;
+        while i < 3 {
+            let x = [
+                nonce[4 * i],
+                nonce[4 * i + 1],
+                nonce[4 * i + 2],
+                nonce[4 * i + 3],
+            ];
+            s.state[13 + i].replace(u32::from_le_bytes(x));
+            i += 1;
+        }
+        s
+    }
+
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 4]) -> Self {
+        let mut key = [0u8; 32];
+        let mut i = 0;
+        while i < 32 {
+            let bytes = words[i / 8].to_le_bytes();
+            let mut j = 0;
+            while j < 8 {
+                key[i + j] = bytes[j];
+                j += 1;
+            }
+            i += 8;
+        }
+        Self::with_key(key)
+    }
+
+    const fn with_key(key: [u8; 32]) -> Self {
+        let () = Self::VALID_ROUNDS;
+        let s = Self {
+            state: [const { Cell::new(0) }; 16],
+            buffer: [const { Cell::new(0) }; 16],
+            index: Cell::new(16),
+        };
+        s.set_key(key);
+        s
+    }
+
+    const fn set_key(&self, key: [u8; 32]) {
+        let mut i = 0;
+        while i < 16 {
+            let word = match i {
+                0..4 => CHACHA_CONSTANTS[i],
+                4..12 => {
+                    let j = 4 * (i - 4);
+                    u32::from_le_bytes([key[j], key[j + 1], key[j + 2], key[j + 3]])
+                }
+                _ => 0,
+            };
+            self.state[i].replace(word);
+            i += 1;
+        }
+        self.index.replace(16);
+    }
+
+    #[inline(never)]
+    fn refill(&self) {
+        let () = Self::VALID_ROUNDS;
+        let input = self.state.each_ref().map(Cell::get);
+        let mut x = input;
+
+        for _ in 0..ROUNDS / 2 {
+            chacha_quarter_round(&mut x, 0, 4, 8, 12);
+            chacha_quarter_round(&mut x, 1, 5, 9, 13);
+            chacha_quarter_round(&mut x, 2, 6, 10, 14);
+            chacha_quarter_round(&mut x, 3, 7, 11, 15);
+
+            chacha_quarter_round(&mut x, 0, 5, 10, 15);
+            chacha_quarter_round(&mut x, 1, 6, 11, 12);
+            chacha_quarter_round(&mut x, 2, 7, 8, 13);
+            chacha_quarter_round(&mut x, 3, 4, 9, 14);
+        }
+
+        self.buffer
+            .iter()
+            .zip(x.iter().zip(input))
+            .for_each(|(word, (x, input))| word.set(x.wrapping_add(input)));
+
+        let counter = ((input[12] as u64) | ((input[13] as u64) << 32)).wrapping_add(1);
+        self.state[12].set(counter as u32);
+        self.state[13].set((counter >> 32) as u32);
+        self.index.set(0);
+    }
+}
+
+impl<const ROUNDS: usize> Default for ChaCha<ROUNDS> {
+    fn default() -> Self {
+        let s = Self::with_key([0; 32]);
+        s.seed();
+        s
+    }
+}
+
+impl<const ROUNDS: usize> Random for ChaCha<ROUNDS> {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        if self.index.get() >= 16 {
+            self.refill();
+        }
+
+        // Keeps an odd index set from outside within the buffer.
+        let index = self.index.get() & 14;
+        self.index.set(index + 2);
+
+        (self.buffer[index].get() as u64) | ((self.buffer[index + 1].get() as u64) << 32)
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl<const ROUNDS: usize> SeedableRandom for ChaCha<ROUNDS> {
+    const SEED_WORDS: usize = 4;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self {
+            state,
+            buffer,
+            index,
+        } = Self::from_words(std::array::from_fn(|_| next()));
+        self.state
+            .iter()
+            .zip(state)
+            .for_each(|(x, state)| x.set(state.into_inner()));
+        self.buffer
+            .iter()
+            .zip(buffer)
+            .for_each(|(x, buffer)| x.set(buffer.into_inner()));
+        self.index.set(index.into_inner());
+    }
+}
+
+impl CryptoRandom for ChaCha8 {}
+impl CryptoRandom for ChaCha12 {}
+impl CryptoRandom for ChaCha20 {}
+
+/// "expand 32-byte k"
+const CHACHA_CONSTANTS: [u32; 4] = [0x61707865, 0x3320646E, 0x79622D32, 0x6B206574];
+
+#[inline(always)]
+fn chacha_quarter_round(x: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
+
+/// Copyright (C) 2004, Makoto Matsumoto and Takuji Nishimura.
+/// Licensed under the 3-clause BSD license.
+///
+/// The 64-bit Mersenne Twister, compatible with `std::mt19937_64` of C++.
+pub struct Mt19937_64 {
+    pub mt: [Cell<u64>; MT19937_64_N],
+    pub index: Cell<usize>,
+}
+
+pub const MT19937_64_N: usize = 312;
+const MT19937_64_M: usize = 156;
+const MT19937_64_MATRIX_A: u64 = 0xB5026F5AA96619E9;
+const MT19937_64_UPPER_MASK: u64 = 0xFFFFFFFF80000000;
+const MT19937_64_LOWER_MASK: u64 = 0x7FFFFFFF;
+
+impl Mt19937_64 {
+    /// The seed `std::mt19937_64` uses when it is default constructed.
+    pub const DEFAULT_SEED: u64 = 5489;
+
+    /// Seeds the generator like `init_genrand64` and `std::mt19937_64::seed`.
+    pub const fn new(seed: u64) -> Self {
+        let s = Self {
+            mt: [const { Cell::new(0) }; MT19937_64_N],
+            index: Cell::new(MT19937_64_N),
+        };
+        s.init(seed);
         s
     }
+
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 1]) -> Self {
+        Self::new(words[0])
+    }
+
+    const fn init(&self, seed: u64) {
+        self.mt[0].replace(seed);
+        let mut i = 1;
+        while i < MT19937_64_N {
+            let prev = self.mt[i - 1].get();
+            self.mt[i].replace(
+                6364136223846793005u64
+                    .wrapping_mul(prev ^ (prev >> 62))
+                    .wrapping_add(i as u64),
+            );
+            i += 1;
+        }
+        self.index.replace(MT19937_64_N);
+    }
+
+    #[inline(never)]
+    fn twist(&self) {
+        let mt = &self.mt;
+        let mix = |i: usize, next: usize, far: usize| {
+            let x =
+                (mt[i].get() & MT19937_64_UPPER_MASK) | (mt[next].get() & MT19937_64_LOWER_MASK);
+            let mag = if x & 1 == 0 { 0 } else { MT19937_64_MATRIX_A };
+            mt[i].set(mt[far].get() ^ (x >> 1) ^ mag);
+        };
+
+        for i in 0..MT19937_64_N - MT19937_64_M {
+            mix(i, i + 1, i + MT19937_64_M);
+        }
+        for i in MT19937_64_N - MT19937_64_M..MT19937_64_N - 1 {
+            mix(i, i + 1, i + MT19937_64_M - MT19937_64_N);
+        }
+        mix(MT19937_64_N - 1, 0, MT19937_64_M - 1);
+
+        self.index.set(0);
+    }
 }
 
-impl Random for Xoshiro256plusplus {
+impl Default for Mt19937_64 {
+    fn default() -> Self {
+        Self::new(os_seeds(1)())
+    }
+}
+
+impl Random for Mt19937_64 {
     #[inline(always)]
     fn u64(&self) -> u64 {
-        #[inline(always)]
-        fn xoshiro_rotl(x: u64, k: u64) -> u64 {
-            (x << k) | (x >> (64 - k))
+        if self.index.get() >= MT19937_64_N {
+            self.twist();
         }
 
-        let result = xoshiro_rotl(self.s0.get() + self.s3.get(), 23) + self.s0.get();
+        let index = self.index.get();
+        self.index.set(index + 1);
 
-        let t = self.s0.get() << 17;
+        let mut x = self.mt[index].get();
+        x ^= (x >> 29) & 0x5555555555555555;
+        x ^= (x << 17) & 0x71D67FFFEDA60000;
+        x ^= (x << 37) & 0xFFF7EEE000000000;
+        x ^ (x >> 43)
+    }
 
-        self.s2.set(self.s2.get() ^ self.s0.get());
-        self.s3.set(self.s3.get() ^ self.s1.get());
-        self.s1.set(self.s1.get() ^ self.s2.get());
-        self.s0.set(self.s0.get() ^ self.s3.get());
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
 
-        self.s2.set(self.s2.get() ^ t);
+impl SeedableRandom for Mt19937_64 {
+    const SEED_WORDS: usize = 1;
 
-        self.s3.set(xoshiro_rotl(self.s3.get(), 45));
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { mt, index } = Self::from_words(std::array::from_fn(|_| next()));
+        self.mt
+            .iter()
+            .zip(mt)
+            .for_each(|(x, mt)| x.set(mt.into_inner()));
+        self.index.set(index.into_inner());
+    }
+}
 
-        result
+/// Written in 2014 by Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+///
+/// Uses the shift triple 23, 17, 26 of the original paper, which V8 also uses
+/// for `Math.random()`.
+pub struct Xorshift128plus {
+    pub s0: Cell<u64>,
+    pub s1: Cell<u64>,
+}
+
+impl Xorshift128plus {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 2]) -> Self {
+        let [s0, s1] = words;
+        Self {
+            s0: Cell::new(s0 | 1),
+            s1: Cell::new(s1 | 1),
+        }
+    }
+}
+
+impl Default for Xorshift128plus {
+    fn default() -> Self {
+        let s = Self {
+            s0: Cell::new(0),
+            s1: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random for Xorshift128plus {
+    #[inline(always)]
+    fn u64(&self) -> u64 {
+        let mut s1 = self.s0.get();
+        let s0 = self.s1.get();
+
+        self.s0.set(s0);
+        s1 ^= s1 << 23;
+        self.s1.set(s1 ^ s0 ^ (s1 >> 17) ^ (s0 >> 26));
+
+        self.s1.get().wrapping_add(s0)
     }
 
     fn seed(&self) {
-        self.s0.set(get_seed() | 1);
-        self.s1.set(get_seed() | 1);
-        self.s2.set(get_seed() | 1);
-        self.s3.set(get_seed() | 1);
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom for Xorshift128plus {
+    const SEED_WORDS: usize = 2;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { s0, s1 } = Self::from_words(std::array::from_fn(|_| next()));
+        self.s0.set(s0.into_inner());
+        self.s1.set(s1.into_inner());
     }
 }
 
 /// Copyright 2014-2019 Melissa O'Neill and the PCG Project contributors.
 /// Licensed under MIT or Apache-2.0.
-pub struct Pcg64 {
-    pub state: Cell<u128>,
-    pub inc: Cell<u128>,
+///
+/// The `pcg32` generator (XSH-RR output on a 64-bit LCG).
+pub struct Pcg32 {
+    pub state: Cell<u64>,
+    pub inc: Cell<u64>, // Needs to be odd!
 }
 
-impl Default for Pcg64 {
-    fn default() -> Self {
+impl Pcg32 {
+    /// Seeds the generator like `pcg32_srandom_r` of the reference implementation.
+    pub fn new(initstate: u64, initseq: u64) -> Self {
+        let s = Self {
+            state: Cell::new(0),
+            inc: Cell::new((initseq << 1) | 1),
+        };
+        s.u32();
+        s.state.set(s.state.get().wrapping_add(initstate));
+        s.u32();
+        s
+    }
+
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 2]) -> Self {
+        let [state, inc] = words;
         Self {
-            state: Cell::new((((get_seed() as u128) << 64) + get_seed() as u128) | 1),
-            inc: Cell::new((((get_seed() as u128) << 64) + get_seed() as u128) | 1),
+            state: Cell::new(state),
+            inc: Cell::new(inc | 1),
         }
     }
 }
 
-impl Random for Pcg64 {
+impl Default for Pcg32 {
+    fn default() -> Self {
+        let s = Self {
+            state: Cell::new(0),
+            inc: Cell::new(1),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random32 for Pcg32 {
     #[inline(always)]
-    fn u64(&self) -> u64 {
+    fn u32(&self) -> u32 {
         let s = self.state.get();
         self.state.set(
-            self.state
-                .get()
-                .wrapping_mul(0x2360ED051FC65DA44385DF649FCCF645)
+            s.wrapping_mul(6364136223846793005)
                 .wrapping_add(self.inc.get()),
         );
 
-        let rot = (s >> 122) as u32;
-        let xsl = ((s >> 64) as u64) ^ (s as u64);
-        xsl.rotate_right(rot)
+        let xorshifted = (((s >> 18) ^ s) >> 27) as u32;
+        xorshifted.rotate_right((s >> 59) as u32)
     }
 
     fn seed(&self) {
-        self.state
-            .set((((get_seed() as u128) << 64) + get_seed() as u128) | 1);
-        self.inc
-            .set((((get_seed() as u128) << 64) + get_seed() as u128) | 1);
+        self.seed_from(os_seeds(Self::SEED_WORDS))
     }
 }
 
-/// Copyright 2014-2019 Melissa O'Neill and the PCG Project contributors.
-/// Licensed under MIT or Apache-2.0.
-pub struct Pcg64Fast {
-    pub state: Cell<u128>,
+impl SeedableRandom32 for Pcg32 {
+    const SEED_WORDS: usize = 2;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { state, inc } = Self::from_words(std::array::from_fn(|_| next()));
+        self.state.set(state.into_inner());
+        self.inc.set(inc.into_inner());
+    }
 }
 
-impl Default for Pcg64Fast {
+/// Written in 2019 by David Blackman and Sebastiano Vigna.
+///
+/// To the extent possible under law, the author has dedicated all copyright
+/// and related and neighboring rights to this software to the public domain
+/// worldwide. This software is distributed without any warranty.
+///
+/// See <http://creativecommons.org/publicdomain/zero/1.0/>.
+pub struct Xoshiro128plusplus {
+    pub s0: Cell<u32>,
+    pub s1: Cell<u32>,
+    pub s2: Cell<u32>,
+    pub s3: Cell<u32>,
+}
+
+impl Xoshiro128plusplus {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 4]) -> Self {
+        let [s0, s1, s2, s3] = words;
+        Self {
+            s0: Cell::new(s0 as u32 | 1),
+            s1: Cell::new(s1 as u32 | 1),
+            s2: Cell::new(s2 as u32 | 1),
+            s3: Cell::new(s3 as u32 | 1),
+        }
+    }
+}
+
+impl Default for Xoshiro128plusplus {
     fn default() -> Self {
         let s = Self {
-            state: Cell::new(0),
+            s0: Cell::new(0),
+            s1: Cell::new(0),
+            s2: Cell::new(0),
+            s3: Cell::new(0),
         };
         s.seed();
         s
     }
 }
 
-impl Random for Pcg64Fast {
+impl Random32 for Xoshiro128plusplus {
     #[inline(always)]
-    fn u64(&self) -> u64 {
-        let s = self.state.get();
-        self.state.set(
-            self.state
-                .get()
-                .wrapping_mul(0x2360ED051FC65DA44385DF649FCCF645),
-        );
+    fn u32(&self) -> u32 {
+        let result = (self.s0.get().wrapping_add(self.s3.get()))
+            .rotate_left(7)
+            .wrapping_add(self.s0.get());
+
+        let t = self.s1.get() << 9;
+
+        self.s2.set(self.s2.get() ^ self.s0.get());
+        self.s3.set(self.s3.get() ^ self.s1.get());
+        self.s1.set(self.s1.get() ^ self.s2.get());
+        self.s0.set(self.s0.get() ^ self.s3.get());
 
-        let rot = (s >> 122) as u32;
-        let xsl = ((s >> 64) as u64) ^ (s as u64);
-        xsl.rotate_right(rot)
+        self.s2.set(self.s2.get() ^ t);
+
+        self.s3.set(self.s3.get().rotate_left(11));
+
+        result
     }
 
     fn seed(&self) {
-        self.state
-            .set((((get_seed() as u128) << 64) + get_seed() as u128) | 1);
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom32 for Xoshiro128plusplus {
+    const SEED_WORDS: usize = 4;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { s0, s1, s2, s3 } = Self::from_words(std::array::from_fn(|_| next()));
+        self.s0.set(s0.into_inner());
+        self.s1.set(s1.into_inner());
+        self.s2.set(s2.into_inner());
+        self.s3.set(s3.into_inner());
+    }
+}
+
+/// Chris Doty-Humphrey's Small Fast Counting generator, 32-bit variant as found
+/// in PractRand.
+///
+/// Public domain.
+pub struct Sfc32 {
+    pub a: Cell<u32>,
+    pub b: Cell<u32>,
+    pub c: Cell<u32>,
+    pub counter: Cell<u32>,
+}
+
+impl Sfc32 {
+    /// Seeds the generator the way PractRand does for a single 64-bit seed.
+    pub const fn new(seed: u64) -> Self {
+        let s = Self {
+            a: Cell::new(0),
+            b: Cell::new(seed as u32),
+            c: Cell::new((seed >> 32) as u32),
+            counter: Cell::new(1),
+        };
+        s.warm_up();
+        s
+    }
+
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 2]) -> Self {
+        let [seed, a] = words;
+        let s = Self {
+            a: Cell::new((a >> 32) as u32),
+            b: Cell::new(seed as u32),
+            c: Cell::new((seed >> 32) as u32),
+            counter: Cell::new(1),
+        };
+        s.warm_up();
+        s
+    }
+
+    /// `u32()` as a const function, to build tables at compile time.
+    #[inline(always)]
+    pub const fn next_u32(&self) -> u32 {
+        let a = self.a.get();
+        let b = self.b.get();
+        let c = self.c.get();
+        let counter = self.counter.get();
+
+        let tmp = a.wrapping_add(b).wrapping_add(counter);
+        self.counter.replace(counter.wrapping_add(1));
+        self.a.replace(b ^ (b >> 9));
+        self.b.replace(c.wrapping_add(c << 3));
+        self.c.replace(c.rotate_left(21).wrapping_add(tmp));
+
+        tmp
+    }
+
+    const fn warm_up(&self) {
+        let mut i = 0;
+        while i < 12 {
+            self.next_u32();
+            i += 1;
+        }
+    }
+}
+
+impl Default for Sfc32 {
+    fn default() -> Self {
+        let s = Self {
+            a: Cell::new(0),
+            b: Cell::new(0),
+            c: Cell::new(0),
+            counter: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random32 for Sfc32 {
+    #[inline(always)]
+    fn u32(&self) -> u32 {
+        self.next_u32()
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom32 for Sfc32 {
+    const SEED_WORDS: usize = 2;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { a, b, c, counter } = Self::from_words(std::array::from_fn(|_| next()));
+        self.a.set(a.into_inner());
+        self.b.set(b.into_inner());
+        self.c.set(c.into_inner());
+        self.counter.set(counter.into_inner());
+    }
+}
+
+/// Copyright 2020 Mark A. Overton
+/// Licensed under Apache-2.0.
+pub struct RomuTrio32 {
+    pub x: Cell<u32>,
+    pub y: Cell<u32>,
+    pub z: Cell<u32>,
+}
+
+impl RomuTrio32 {
+    /// Seeds the generator like `seed_from_u64()`, but in const contexts.
+    pub const fn from_seed(seed: u64) -> Self {
+        Self::from_words(const_seeds(seed))
+    }
+
+    /// Seeds the generator from the words `seed_from()` takes, but in const contexts.
+    pub const fn from_words(words: [u64; 3]) -> Self {
+        let [x, y, z] = words;
+        Self {
+            x: Cell::new(x as u32 | 1),
+            y: Cell::new(y as u32 | 1),
+            z: Cell::new(z as u32 | 1),
+        }
+    }
+}
+
+impl Default for RomuTrio32 {
+    fn default() -> Self {
+        let s = Self {
+            x: Cell::new(0),
+            y: Cell::new(0),
+            z: Cell::new(0),
+        };
+        s.seed();
+        s
+    }
+}
+
+impl Random32 for RomuTrio32 {
+    #[inline(always)]
+    fn u32(&self) -> u32 {
+        let xp = self.x.get();
+        let yp = self.y.get();
+        let zp = self.z.get();
+
+        self.x.set(3323815723u32.wrapping_mul(zp));
+        self.y.set(yp.wrapping_sub(xp).rotate_left(6));
+        self.z.set(zp.wrapping_sub(yp).rotate_left(22));
+
+        xp
+    }
+
+    fn seed(&self) {
+        self.seed_from(os_seeds(Self::SEED_WORDS))
+    }
+}
+
+impl SeedableRandom32 for RomuTrio32 {
+    const SEED_WORDS: usize = 3;
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        let Self { x, y, z } = Self::from_words(std::array::from_fn(|_| next()));
+        self.x.set(x.into_inner());
+        self.y.set(y.into_inner());
+        self.z.set(z.into_inner());
     }
 }
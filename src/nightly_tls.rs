@@ -1,8 +1,11 @@
 use std::cell::Cell;
 
 use crate::{
-    Lehmer64, Mwc256XXA64, Pcg64, Pcg64Fast, Random, RomuJr, RomuTrio, Splitmix64, Tylo64, Wyrand,
-    Xoshiro256plusplus,
+    ChaCha12, ChaCha20, ChaCha8, Jsf64, Lehmer64, Mt19937_64, Mwc, Mwc128, Mwc128XXA32, Mwc192,
+    Mwc256, Mwc256XXA64, Mwc32, Pcg64, Pcg64Dxsm, Pcg64DxsmFast, Pcg64Fast, Random, RomuDuo,
+    RomuJr, RomuQuad, RomuTrio, Sfc64, Splitmix64, Tylo64, Widen, Wyrand, Xoroshiro1024plusplus,
+    Xoroshiro128plus, Xoroshiro128plusplus, Xoroshiro128starstar, Xorshift128plus, Xoshiro256plus,
+    Xoshiro256plusplus, Xoshiro256starstar, Xoshiro512plusplus, MT19937_64_N,
 };
 
 #[thread_local]
@@ -73,10 +76,10 @@ pub fn lehmer64_u64() -> u64 {
 
 #[thread_local]
 pub(crate) static MWC256XXA64: Mwc256XXA64 = Mwc256XXA64 {
-    x1: Cell::new(3),
-    x2: Cell::new(3),
-    x3: Cell::new(3),
-    c: Cell::new(3),
+    mwc: Mwc {
+        x: [const { Cell::new(3) }; 3],
+        c: Cell::new(3),
+    },
 };
 
 #[inline(always)]
@@ -167,3 +170,374 @@ pub fn pcg64fast_seed() {
 pub fn pcg64fast_u64() -> u64 {
     PCG64FAST.u64()
 }
+
+#[thread_local]
+pub(crate) static PCG64DXSM: Pcg64Dxsm = Pcg64Dxsm {
+    state: Cell::new(3),
+    inc: Cell::new(3),
+};
+
+#[inline(always)]
+pub fn pcg64dxsm_seed() {
+    PCG64DXSM.seed()
+}
+
+#[inline(always)]
+pub fn pcg64dxsm_u64() -> u64 {
+    PCG64DXSM.u64()
+}
+
+#[thread_local]
+pub(crate) static PCG64DXSMFAST: Pcg64DxsmFast = Pcg64DxsmFast {
+    state: Cell::new(3),
+};
+
+#[inline(always)]
+pub fn pcg64dxsmfast_seed() {
+    PCG64DXSMFAST.seed()
+}
+
+#[inline(always)]
+pub fn pcg64dxsmfast_u64() -> u64 {
+    PCG64DXSMFAST.u64()
+}
+
+#[thread_local]
+pub(crate) static XOSHIRO256STARSTAR: Xoshiro256starstar = Xoshiro256starstar {
+    s0: Cell::new(3),
+    s1: Cell::new(3),
+    s2: Cell::new(3),
+    s3: Cell::new(3),
+};
+
+#[inline(always)]
+pub fn xoshiro256starstar_seed() {
+    XOSHIRO256STARSTAR.seed()
+}
+
+#[inline(always)]
+pub fn xoshiro256starstar_u64() -> u64 {
+    XOSHIRO256STARSTAR.u64()
+}
+
+#[thread_local]
+pub(crate) static XOSHIRO256PLUS: Xoshiro256plus = Xoshiro256plus {
+    s0: Cell::new(3),
+    s1: Cell::new(3),
+    s2: Cell::new(3),
+    s3: Cell::new(3),
+};
+
+#[inline(always)]
+pub fn xoshiro256plus_seed() {
+    XOSHIRO256PLUS.seed()
+}
+
+#[inline(always)]
+pub fn xoshiro256plus_u64() -> u64 {
+    XOSHIRO256PLUS.u64()
+}
+
+#[thread_local]
+pub(crate) static XOSHIRO512PLUSPLUS: Xoshiro512plusplus = Xoshiro512plusplus {
+    s: [const { Cell::new(3) }; 8],
+};
+
+#[inline(always)]
+pub fn xoshiro512plusplus_seed() {
+    XOSHIRO512PLUSPLUS.seed()
+}
+
+#[inline(always)]
+pub fn xoshiro512plusplus_u64() -> u64 {
+    XOSHIRO512PLUSPLUS.u64()
+}
+
+#[thread_local]
+pub(crate) static XOROSHIRO128PLUSPLUS: Xoroshiro128plusplus = Xoroshiro128plusplus {
+    s0: Cell::new(3),
+    s1: Cell::new(3),
+};
+
+#[inline(always)]
+pub fn xoroshiro128plusplus_seed() {
+    XOROSHIRO128PLUSPLUS.seed()
+}
+
+#[inline(always)]
+pub fn xoroshiro128plusplus_u64() -> u64 {
+    XOROSHIRO128PLUSPLUS.u64()
+}
+
+#[thread_local]
+pub(crate) static XOROSHIRO128STARSTAR: Xoroshiro128starstar = Xoroshiro128starstar {
+    s0: Cell::new(3),
+    s1: Cell::new(3),
+};
+
+#[inline(always)]
+pub fn xoroshiro128starstar_seed() {
+    XOROSHIRO128STARSTAR.seed()
+}
+
+#[inline(always)]
+pub fn xoroshiro128starstar_u64() -> u64 {
+    XOROSHIRO128STARSTAR.u64()
+}
+
+#[thread_local]
+pub(crate) static XOROSHIRO128PLUS: Xoroshiro128plus = Xoroshiro128plus {
+    s0: Cell::new(3),
+    s1: Cell::new(3),
+};
+
+#[inline(always)]
+pub fn xoroshiro128plus_seed() {
+    XOROSHIRO128PLUS.seed()
+}
+
+#[inline(always)]
+pub fn xoroshiro128plus_u64() -> u64 {
+    XOROSHIRO128PLUS.u64()
+}
+
+#[thread_local]
+pub(crate) static XOROSHIRO1024PLUSPLUS: Xoroshiro1024plusplus = Xoroshiro1024plusplus {
+    s: [const { Cell::new(3) }; 16],
+    p: Cell::new(0),
+};
+
+#[inline(always)]
+pub fn xoroshiro1024plusplus_seed() {
+    XOROSHIRO1024PLUSPLUS.seed()
+}
+
+#[inline(always)]
+pub fn xoroshiro1024plusplus_u64() -> u64 {
+    XOROSHIRO1024PLUSPLUS.u64()
+}
+
+#[thread_local]
+pub(crate) static ROMUQUAD: RomuQuad = RomuQuad {
+    w: Cell::new(3),
+    x: Cell::new(3),
+    y: Cell::new(3),
+    z: Cell::new(3),
+};
+
+#[inline(always)]
+pub fn romu_quad_seed() {
+    ROMUQUAD.seed()
+}
+
+#[inline(always)]
+pub fn romu_quad_u64() -> u64 {
+    ROMUQUAD.u64()
+}
+
+#[thread_local]
+pub(crate) static ROMUDUO: RomuDuo = RomuDuo {
+    x: Cell::new(3),
+    y: Cell::new(3),
+};
+
+#[inline(always)]
+pub fn romu_duo_seed() {
+    ROMUDUO.seed()
+}
+
+#[inline(always)]
+pub fn romu_duo_u64() -> u64 {
+    ROMUDUO.u64()
+}
+
+#[thread_local]
+pub(crate) static SFC64: Sfc64 = Sfc64 {
+    a: Cell::new(3),
+    b: Cell::new(3),
+    c: Cell::new(3),
+    counter: Cell::new(1),
+};
+
+#[inline(always)]
+pub fn sfc64_seed() {
+    SFC64.seed()
+}
+
+#[inline(always)]
+pub fn sfc64_u64() -> u64 {
+    SFC64.u64()
+}
+
+#[thread_local]
+pub(crate) static JSF64: Jsf64 = Jsf64 {
+    a: Cell::new(3),
+    b: Cell::new(3),
+    c: Cell::new(3),
+    d: Cell::new(3),
+};
+
+#[inline(always)]
+pub fn jsf64_seed() {
+    JSF64.seed()
+}
+
+#[inline(always)]
+pub fn jsf64_u64() -> u64 {
+    JSF64.u64()
+}
+
+#[thread_local]
+pub(crate) static CHACHA8: ChaCha8 = ChaCha8 {
+    state: [const { Cell::new(3) }; 16],
+    buffer: [const { Cell::new(3) }; 16],
+    index: Cell::new(16),
+};
+
+#[inline(always)]
+pub fn chacha8_seed() {
+    CHACHA8.seed()
+}
+
+#[inline(always)]
+pub fn chacha8_u64() -> u64 {
+    CHACHA8.u64()
+}
+
+#[thread_local]
+pub(crate) static CHACHA12: ChaCha12 = ChaCha12 {
+    state: [const { Cell::new(3) }; 16],
+    buffer: [const { Cell::new(3) }; 16],
+    index: Cell::new(16),
+};
+
+#[inline(always)]
+pub fn chacha12_seed() {
+    CHACHA12.seed()
+}
+
+#[inline(always)]
+pub fn chacha12_u64() -> u64 {
+    CHACHA12.u64()
+}
+
+#[thread_local]
+pub(crate) static CHACHA20: ChaCha20 = ChaCha20 {
+    state: [const { Cell::new(3) }; 16],
+    buffer: [const { Cell::new(3) }; 16],
+    index: Cell::new(16),
+};
+
+#[inline(always)]
+pub fn chacha20_seed() {
+    CHACHA20.seed()
+}
+
+#[inline(always)]
+pub fn chacha20_u64() -> u64 {
+    CHACHA20.u64()
+}
+
+#[thread_local]
+pub(crate) static MT19937_64: Mt19937_64 = Mt19937_64 {
+    mt: [const { Cell::new(3) }; MT19937_64_N],
+    index: Cell::new(MT19937_64_N),
+};
+
+#[inline(always)]
+pub fn mt19937_64_seed() {
+    MT19937_64.seed()
+}
+
+#[inline(always)]
+pub fn mt19937_64_u64() -> u64 {
+    MT19937_64.u64()
+}
+
+#[thread_local]
+pub(crate) static XORSHIFT128PLUS: Xorshift128plus = Xorshift128plus {
+    s0: Cell::new(3),
+    s1: Cell::new(3),
+};
+
+#[inline(always)]
+pub fn xorshift128plus_seed() {
+    XORSHIFT128PLUS.seed()
+}
+
+#[inline(always)]
+pub fn xorshift128plus_u64() -> u64 {
+    XORSHIFT128PLUS.u64()
+}
+
+#[thread_local]
+pub(crate) static MWC128: Mwc128 = Mwc128 {
+    mwc: Mwc {
+        x: [const { Cell::new(3) }; 1],
+        c: Cell::new(3),
+    },
+};
+
+#[inline(always)]
+pub fn mwc128_seed() {
+    MWC128.seed()
+}
+
+#[inline(always)]
+pub fn mwc128_u64() -> u64 {
+    MWC128.u64()
+}
+
+#[thread_local]
+pub(crate) static MWC192: Mwc192 = Mwc192 {
+    mwc: Mwc {
+        x: [const { Cell::new(3) }; 2],
+        c: Cell::new(3),
+    },
+};
+
+#[inline(always)]
+pub fn mwc192_seed() {
+    MWC192.seed()
+}
+
+#[inline(always)]
+pub fn mwc192_u64() -> u64 {
+    MWC192.u64()
+}
+
+#[thread_local]
+pub(crate) static MWC256: Mwc256 = Mwc256 {
+    mwc: Mwc {
+        x: [const { Cell::new(3) }; 3],
+        c: Cell::new(3),
+    },
+};
+
+#[inline(always)]
+pub fn mwc256_seed() {
+    MWC256.seed()
+}
+
+#[inline(always)]
+pub fn mwc256_u64() -> u64 {
+    MWC256.u64()
+}
+
+#[thread_local]
+pub(crate) static MWC128XXA32: Widen<Mwc128XXA32> = Widen(Mwc128XXA32 {
+    mwc: Mwc32 {
+        x: [const { Cell::new(3) }; 3],
+        c: Cell::new(3),
+    },
+});
+
+#[inline(always)]
+pub fn mwc128xxa32_seed() {
+    MWC128XXA32.seed()
+}
+
+#[inline(always)]
+pub fn mwc128xxa32_u64() -> u64 {
+    MWC128XXA32.u64()
+}
@@ -0,0 +1,508 @@
+//! Reduced-width variants of chaotic and multiply-based generators, following
+//! PractRand's advice to judge a design by scaling it down: the same algorithm
+//! on 8-, 16- and 32-bit words fails statistical tests after far fewer bytes,
+//! so comparing the failure points across widths hints at how much margin the
+//! full-size generator has.
+//!
+//! Shift and rotation amounts are scaled with the word size and rounded half
+//! up, so 5.5 becomes 6.
+//! Multipliers of the MWC variants are the largest `A` below 2^w for which
+//! `A * 2^(3w) - 1` is a safe prime.
+//!
+//! `fill_raw()` writes the native words of each width, so the output can be
+//! piped into PractRand's `RNG_test stdin8`, `stdin16`, `stdin32` or `stdin64`.
+//!
+//! The variants with small states also implement `SmallState`, which lets
+//! `find_cycle()` measure the cycle a seed ends up on.
+
+use std::cell::Cell;
+
+use crate::{
+    mwc_core, os_seeds, splitmix_seeds, Mwc128XXA32, Mwc256XXA64, Random, Random32, RomuJr,
+    RomuTrio, RomuTrio32, SeedableRandom, SeedableRandom32, Tylo64,
+};
+
+/// A generator on words of `BITS` bits.
+pub trait ReducedRandom {
+    /// The width of the words, and of every output.
+    const BITS: u32;
+
+    /// The number of words `seed_from()` consumes.
+    const SEED_WORDS: usize;
+
+    /// Returns the next output in the low `BITS` bits.
+    fn word(&self) -> u64;
+
+    /// Initializes the state from the words returned by `next`, fixing up
+    /// states the generator can't work with.
+    fn seed_from(&self, next: impl FnMut() -> u64);
+
+    /// Seeds the generator deterministically by expanding `seed` with SplitMix64.
+    fn seed_from_u64(&self, seed: u64) {
+        self.seed_from(splitmix_seeds(seed))
+    }
+
+    /// Fills `bytes` with consecutive outputs in little-endian order, `BITS / 8`
+    /// bytes each. Panics if the length is not a multiple of that.
+    fn fill_raw(&self, bytes: &mut [u8]) {
+        let size = Self::BITS as usize / 8;
+        assert!(
+            bytes.len().is_multiple_of(size),
+            "length is not a multiple of the word size"
+        );
+        for chunk in bytes.chunks_exact_mut(size) {
+            chunk.copy_from_slice(&self.word().to_le_bytes()[..size]);
+        }
+    }
+}
+
+/// Generators whose whole state fits into a `u128`, so that cycle detection
+/// can save and compare it.
+pub trait SmallState: ReducedRandom {
+    /// The number of bits in the state, including constant parts like the
+    /// increment of a Weyl sequence.
+    const STATE_BITS: u32;
+
+    fn state(&self) -> u128;
+
+    fn set_state(&self, state: u128);
+}
+
+/// The cycle a sequence of states runs into: after `tail` steps, the states
+/// repeat every `length` steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub tail: u64,
+    pub length: u64,
+}
+
+/// Finds the cycle reached from the current state of `rng` with Brent's
+/// algorithm, or returns `None` if the cycle or the tail is longer than
+/// `max_steps`. Leaves `rng` somewhere on the cycle.
+///
+/// Richard P. Brent, An improved Monte Carlo factorization algorithm. BIT
+/// Numerical Mathematics 20.2 (1980): 176-184.
+pub fn find_cycle<R: SmallState>(rng: &R, max_steps: u64) -> Option<Cycle> {
+    let start = rng.state();
+
+    // The tortoise teleports to the hare whenever the hare has taken the next
+    // power of two steps, until the hare runs into it.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start;
+    rng.word();
+    while rng.state() != tortoise {
+        if length == power {
+            tortoise = rng.state();
+            power *= 2;
+            length = 0;
+        }
+        rng.word();
+        length += 1;
+        if length > max_steps {
+            return None;
+        }
+    }
+
+    // With the hare `length` steps ahead, both meet at the start of the cycle.
+    rng.set_state(start);
+    for _ in 0..length {
+        rng.word();
+    }
+    let mut hare = rng.state();
+    let mut tortoise = start;
+    let mut tail = 0;
+    let step = |state| {
+        rng.set_state(state);
+        rng.word();
+        rng.state()
+    };
+    while tortoise != hare {
+        tortoise = step(tortoise);
+        hare = step(hare);
+        tail += 1;
+        if tail > max_steps {
+            return None;
+        }
+    }
+
+    Some(Cycle { tail, length })
+}
+
+/// Packs `words` into a `u128`, the first word becoming the lowest bits.
+#[inline(always)]
+fn pack(words: &[u64], bits: u32) -> u128 {
+    words
+        .iter()
+        .rev()
+        .fold(0, |state, &word| (state << bits) | word as u128)
+}
+
+/// Unpacks `N` words of `bits` bits packed by `pack()`.
+#[inline(always)]
+fn unpack<const N: usize>(state: u128, bits: u32) -> [u64; N] {
+    let mask = u128::MAX >> (128 - bits);
+    std::array::from_fn(|i| ((state >> (i as u32 * bits)) & mask) as u64)
+}
+
+macro_rules! romu_jr {
+    ($(#[$meta:meta])* $name:ident, $word:ty, $multiplier:expr, $rot:expr) => {
+        $(#[$meta])*
+        pub struct $name {
+            pub x: Cell<$word>,
+            pub y: Cell<$word>,
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                let s = Self {
+                    x: Cell::new(0),
+                    y: Cell::new(0),
+                };
+                s.seed_from(os_seeds(Self::SEED_WORDS));
+                s
+            }
+        }
+
+        impl ReducedRandom for $name {
+            const BITS: u32 = <$word>::BITS;
+            const SEED_WORDS: usize = 2;
+
+            #[inline(always)]
+            fn word(&self) -> u64 {
+                let xp = self.x.get();
+
+                self.x.set(self.y.get().wrapping_mul($multiplier));
+                self.y.set(self.y.get().wrapping_sub(xp).rotate_left($rot));
+
+                xp as u64
+            }
+
+            fn seed_from(&self, mut next: impl FnMut() -> u64) {
+                self.x.set(next() as $word | 1);
+                self.y.set(next() as $word | 1);
+            }
+        }
+
+        impl SmallState for $name {
+            const STATE_BITS: u32 = 2 * <$word>::BITS;
+
+            fn state(&self) -> u128 {
+                pack(&[self.x.get() as u64, self.y.get() as u64], Self::BITS)
+            }
+
+            fn set_state(&self, state: u128) {
+                let [x, y] = unpack(state, Self::BITS);
+                self.x.set(x as $word);
+                self.y.set(y as $word);
+            }
+        }
+    };
+}
+
+romu_jr!(
+    /// `RomuJr` on 8-bit words, with the low bits of the multiplier of
+    /// `RomuTrio32`.
+    RomuJr8,
+    u8,
+    0x2B,
+    3
+);
+
+romu_jr!(
+    /// `RomuJr` on 16-bit words, with the low bits of the multiplier of
+    /// `RomuTrio32`.
+    RomuJr16,
+    u16,
+    0x672B,
+    7
+);
+
+romu_jr!(
+    /// `RomuJr` on 32-bit words, with the multiplier of `RomuTrio32`.
+    RomuJr32,
+    u32,
+    3323815723,
+    14
+);
+
+macro_rules! romu_trio {
+    ($(#[$meta:meta])* $name:ident, $word:ty, $multiplier:expr, $rot_y:expr, $rot_z:expr) => {
+        $(#[$meta])*
+        pub struct $name {
+            pub x: Cell<$word>,
+            pub y: Cell<$word>,
+            pub z: Cell<$word>,
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                let s = Self {
+                    x: Cell::new(0),
+                    y: Cell::new(0),
+                    z: Cell::new(0),
+                };
+                s.seed_from(os_seeds(Self::SEED_WORDS));
+                s
+            }
+        }
+
+        impl ReducedRandom for $name {
+            const BITS: u32 = <$word>::BITS;
+            const SEED_WORDS: usize = 3;
+
+            #[inline(always)]
+            fn word(&self) -> u64 {
+                let xp = self.x.get();
+                let yp = self.y.get();
+                let zp = self.z.get();
+
+                self.x.set(zp.wrapping_mul($multiplier));
+                self.y.set(yp.wrapping_sub(xp).rotate_left($rot_y));
+                self.z.set(zp.wrapping_sub(yp).rotate_left($rot_z));
+
+                xp as u64
+            }
+
+            fn seed_from(&self, mut next: impl FnMut() -> u64) {
+                self.x.set(next() as $word | 1);
+                self.y.set(next() as $word | 1);
+                self.z.set(next() as $word | 1);
+            }
+        }
+
+        impl SmallState for $name {
+            const STATE_BITS: u32 = 3 * <$word>::BITS;
+
+            fn state(&self) -> u128 {
+                let words = [self.x.get(), self.y.get(), self.z.get()];
+                pack(&words.map(|w| w as u64), Self::BITS)
+            }
+
+            fn set_state(&self, state: u128) {
+                let [x, y, z] = unpack(state, Self::BITS);
+                self.x.set(x as $word);
+                self.y.set(y as $word);
+                self.z.set(z as $word);
+            }
+        }
+    };
+}
+
+romu_trio!(
+    /// `RomuTrio` on 8-bit words, with the low bits of the multiplier of
+    /// `RomuTrio32`.
+    RomuTrio8,
+    u8,
+    0x2B,
+    2,
+    6
+);
+
+romu_trio!(
+    /// `RomuTrio` on 16-bit words, with the low bits of the multiplier of
+    /// `RomuTrio32`.
+    RomuTrio16,
+    u16,
+    0x672B,
+    3,
+    11
+);
+
+macro_rules! tylo {
+    ($(#[$meta:meta])* $name:ident, $word:ty, $shift:expr, $rot:expr) => {
+        $(#[$meta])*
+        pub struct $name {
+            pub a: Cell<$word>,
+            pub b: Cell<$word>,
+            pub w: Cell<$word>,
+            pub k: Cell<$word>, // Needs to be odd!
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                let s = Self {
+                    a: Cell::new(0),
+                    b: Cell::new(0),
+                    w: Cell::new(0),
+                    k: Cell::new(0),
+                };
+                s.seed_from(os_seeds(Self::SEED_WORDS));
+                s
+            }
+        }
+
+        impl ReducedRandom for $name {
+            const BITS: u32 = <$word>::BITS;
+            const SEED_WORDS: usize = 4;
+
+            #[inline(always)]
+            fn word(&self) -> u64 {
+                let b = self.b.get();
+
+                self.w.set(self.w.get().wrapping_add(self.k.get()));
+                let out = self.a.get() ^ self.w.get();
+
+                self.a.set(b.wrapping_add(b << 3) ^ (b >> $shift));
+                self.b.set(b.rotate_left($rot).wrapping_add(out));
+
+                out as u64
+            }
+
+            fn seed_from(&self, mut next: impl FnMut() -> u64) {
+                self.k.set(next() as $word | 1);
+                self.a.set(next() as $word | 1);
+                self.b.set(next() as $word | 1);
+                self.w.set(next() as $word | 1);
+            }
+        }
+
+        impl SmallState for $name {
+            const STATE_BITS: u32 = 4 * <$word>::BITS;
+
+            fn state(&self) -> u128 {
+                let words = [self.a.get(), self.b.get(), self.w.get(), self.k.get()];
+                pack(&words.map(|w| w as u64), Self::BITS)
+            }
+
+            fn set_state(&self, state: u128) {
+                let [a, b, w, k] = unpack(state, Self::BITS);
+                self.a.set(a as $word);
+                self.b.set(b as $word);
+                self.w.set(w as $word);
+                self.k.set(k as $word);
+            }
+        }
+    };
+}
+
+tylo!(
+    /// `Tylo64` on 8-bit words.
+    Tylo8,
+    u8,
+    1,
+    3
+);
+
+tylo!(
+    /// `Tylo64` on 16-bit words.
+    Tylo16,
+    u16,
+    3,
+    6
+);
+
+tylo!(
+    /// `Tylo64` on 32-bit words.
+    Tylo32,
+    u32,
+    6,
+    12
+);
+
+mwc_core!(
+    /// Generic Marsaglia multiply-with-carry generator on 16-bit words with lag
+    /// `LAG` and multiplier `A`.
+    Mwc16,
+    u16,
+    u32
+);
+
+mwc_core!(
+    /// Generic Marsaglia multiply-with-carry generator on 8-bit words with lag
+    /// `LAG` and multiplier `A`.
+    Mwc8,
+    u8,
+    u16
+);
+
+macro_rules! mwc_xxa {
+    ($(#[$meta:meta])* $name:ident, $mwc:ident, $word:ty, $double:ty, $a:expr) => {
+        $(#[$meta])*
+        #[derive(Default)]
+        pub struct $name {
+            pub mwc: $mwc<3, $a>,
+        }
+
+        impl ReducedRandom for $name {
+            const BITS: u32 = <$word>::BITS;
+            const SEED_WORDS: usize = 4;
+
+            #[inline(always)]
+            fn word(&self) -> u64 {
+                let [x3, x2, x1] = self.mwc.x.each_ref().map(Cell::get);
+                let high = ((x3 as $double * $a) >> <$word>::BITS) as $word;
+
+                self.mwc.step();
+
+                (x3 ^ x2).wrapping_add(x1 ^ high) as u64
+            }
+
+            fn seed_from(&self, next: impl FnMut() -> u64) {
+                self.mwc.seed_from(next);
+            }
+        }
+
+        impl SmallState for $name {
+            const STATE_BITS: u32 = 4 * <$word>::BITS;
+
+            fn state(&self) -> u128 {
+                let [x3, x2, x1] = self.mwc.x.each_ref().map(Cell::get);
+                let words = [x3, x2, x1, self.mwc.c.get()];
+                pack(&words.map(|w| w as u64), Self::BITS)
+            }
+
+            fn set_state(&self, state: u128) {
+                let [x3, x2, x1, c] = unpack(state, Self::BITS);
+                self.mwc.x[0].set(x3 as $word);
+                self.mwc.x[1].set(x2 as $word);
+                self.mwc.x[2].set(x1 as $word);
+                self.mwc.c.set(c as $word);
+            }
+        }
+    };
+}
+
+mwc_xxa!(
+    /// `Mwc128XXA32` on 8-bit words.
+    Mwc32XXA8,
+    Mwc8,
+    u8,
+    u16,
+    0xE4
+);
+
+mwc_xxa!(
+    /// `Mwc128XXA32` on 16-bit words.
+    Mwc64XXA16,
+    Mwc16,
+    u16,
+    u32,
+    0xFDAA
+);
+
+/// The full-size generators take part with their regular output.
+macro_rules! reduced_random {
+    ($name:ident, $bits:expr, $output:ident, $trait:ident) => {
+        impl ReducedRandom for $name {
+            const BITS: u32 = $bits;
+            const SEED_WORDS: usize = <$name as $trait>::SEED_WORDS;
+
+            #[inline(always)]
+            fn word(&self) -> u64 {
+                self.$output() as u64
+            }
+
+            fn seed_from(&self, next: impl FnMut() -> u64) {
+                $trait::seed_from(self, next);
+            }
+        }
+    };
+}
+
+reduced_random!(RomuJr, 64, u64, SeedableRandom);
+reduced_random!(RomuTrio32, 32, u32, SeedableRandom32);
+reduced_random!(RomuTrio, 64, u64, SeedableRandom);
+reduced_random!(Mwc128XXA32, 32, u32, SeedableRandom32);
+reduced_random!(Mwc256XXA64, 64, u64, SeedableRandom);
+reduced_random!(Tylo64, 64, u64, SeedableRandom);
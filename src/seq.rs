@@ -0,0 +1,127 @@
+//! Shuffling and sampling without replacement, driven by any `Random`
+//! implementor. Every index comes from `Random::bounded_u64()`, so none of
+//! these are biased towards small indices.
+//!
+//! ```
+//! use prng_benchmark::{seq, Xoshiro256plusplus};
+//!
+//! let rng = Xoshiro256plusplus::default();
+//! let mut cards: Vec<u32> = (0..52).collect();
+//! seq::shuffle(&rng, &mut cards);
+//! let hand = seq::choose_multiple(&rng, &cards, 5);
+//! # assert_eq!(hand.len(), 5);
+//! ```
+
+use std::collections::HashMap;
+
+use crate::Random;
+
+/// Returns a uniform index in `0..len`.
+#[inline(always)]
+fn index<R: Random>(rng: &R, len: usize) -> usize {
+    rng.bounded_u64(len as u64) as usize
+}
+
+/// Shuffles `slice` in place with the Fisher-Yates shuffle.
+pub fn shuffle<R: Random, T>(rng: &R, slice: &mut [T]) {
+    for i in (1..slice.len()).rev() {
+        slice.swap(i, index(rng, i + 1));
+    }
+}
+
+/// Moves `amount` uniformly chosen elements in random order to the front of
+/// `slice` and returns them, followed by the rest in unspecified order.
+///
+/// Only takes `amount` steps of the Fisher-Yates shuffle. `amount` is clamped
+/// to the length of `slice`.
+pub fn partial_shuffle<'a, R: Random, T>(
+    rng: &R,
+    slice: &'a mut [T],
+    amount: usize,
+) -> (&'a mut [T], &'a mut [T]) {
+    let len = slice.len();
+    let amount = amount.min(len);
+    for i in 0..amount {
+        slice.swap(i, i + index(rng, len - i));
+    }
+    slice.split_at_mut(amount)
+}
+
+/// Returns a uniformly chosen element, or `None` if `slice` is empty.
+pub fn choose<'a, R: Random, T>(rng: &R, slice: &'a [T]) -> Option<&'a T> {
+    match slice.len() {
+        0 => None,
+        len => Some(&slice[index(rng, len)]),
+    }
+}
+
+/// Returns `amount` distinct elements in random order, or all of them if
+/// `slice` is shorter.
+pub fn choose_multiple<'a, R: Random, T>(rng: &R, slice: &'a [T], amount: usize) -> Vec<&'a T> {
+    sample_indices(rng, slice.len(), amount)
+        .into_iter()
+        .map(|i| &slice[i])
+        .collect()
+}
+
+/// Returns `amount` distinct indices in `0..len` in random order, using
+/// Floyd's algorithm with one bounded integer per index.
+///
+/// Jon Bentley and Bob Floyd, Programming Pearls: A Sample of Brilliance.
+/// Communications of the ACM 30.9 (1987): 754-757.
+pub fn sample_indices<R: Random>(rng: &R, len: usize, amount: usize) -> Vec<usize> {
+    let amount = amount.min(len);
+    let mut indices = Vec::with_capacity(amount);
+    let mut positions = HashMap::with_capacity(amount);
+
+    for j in len - amount..len {
+        let t = index(rng, j + 1);
+        // If `t` was already taken, `j` takes its place and `t` moves to the
+        // end, which keeps the order uniform as well as the set.
+        if let Some(&position) = positions.get(&t) {
+            indices[position] = j;
+            positions.insert(j, position);
+        }
+        positions.insert(t, indices.len());
+        indices.push(t);
+    }
+    indices
+}
+
+/// Returns a uniformly chosen item of `iter` in one pass, or `None` if it is
+/// empty.
+pub fn choose_iter<R: Random, I: IntoIterator>(rng: &R, iter: I) -> Option<I::Item> {
+    let mut chosen = None;
+    for (seen, item) in iter.into_iter().enumerate() {
+        if index(rng, seen + 1) == 0 {
+            chosen = Some(item);
+        }
+    }
+    chosen
+}
+
+/// Returns `amount` distinct items of `iter` in one pass with reservoir
+/// sampling, or all of them if there are fewer. The order of the result is
+/// not random.
+///
+/// Jeffrey S. Vitter, Random Sampling with a Reservoir. ACM Transactions on
+/// Mathematical Software 11.1 (1985): 37-57.
+pub fn reservoir_sample<R: Random, I: IntoIterator>(
+    rng: &R,
+    iter: I,
+    amount: usize,
+) -> Vec<I::Item> {
+    let mut iter = iter.into_iter();
+    let mut reservoir: Vec<I::Item> = iter.by_ref().take(amount).collect();
+    if amount == 0 || reservoir.len() < amount {
+        return reservoir;
+    }
+
+    for (seen, item) in (amount..).zip(iter) {
+        let k = index(rng, seen + 1);
+        if k < amount {
+            reservoir[k] = item;
+        }
+    }
+    reservoir
+}
@@ -1,6 +1,9 @@
 use crate::{
-    Lehmer64, Mwc256XXA64, Pcg64, Pcg64Fast, Random, RomuJr, RomuTrio, Splitmix64, Tylo64, Wyrand,
-    Xoshiro256plusplus,
+    ChaCha12, ChaCha20, ChaCha8, Jsf64, Lehmer64, Mt19937_64, Mwc128, Mwc128XXA32, Mwc192, Mwc256,
+    Mwc256XXA64, Pcg64, Pcg64Dxsm, Pcg64DxsmFast, Pcg64Fast, Random, RomuDuo, RomuJr, RomuQuad,
+    RomuTrio, Sfc64, Splitmix64, Tylo64, Widen, Wyrand, Xoroshiro1024plusplus, Xoroshiro128plus,
+    Xoroshiro128plusplus, Xoroshiro128starstar, Xorshift128plus, Xoshiro256plus,
+    Xoshiro256plusplus, Xoshiro256starstar, Xoshiro512plusplus,
 };
 
 thread_local! {
@@ -142,3 +145,311 @@ pub fn pcg64fast_seed() {
 pub fn pcg64fast_u64() -> u64 {
     PCG64FAST.with(|rng| rng.u64())
 }
+
+thread_local! {
+    static PCG64DXSM: Pcg64Dxsm = Pcg64Dxsm::default();
+}
+
+#[inline(always)]
+pub fn pcg64dxsm_seed() {
+    PCG64DXSM.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn pcg64dxsm_u64() -> u64 {
+    PCG64DXSM.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static PCG64DXSMFAST: Pcg64DxsmFast = Pcg64DxsmFast::default();
+}
+
+#[inline(always)]
+pub fn pcg64dxsmfast_seed() {
+    PCG64DXSMFAST.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn pcg64dxsmfast_u64() -> u64 {
+    PCG64DXSMFAST.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static XOSHIRO256STARSTAR: Xoshiro256starstar = Xoshiro256starstar::default();
+}
+
+#[inline(always)]
+pub fn xoshiro256starstar_seed() {
+    XOSHIRO256STARSTAR.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn xoshiro256starstar_u64() -> u64 {
+    XOSHIRO256STARSTAR.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static XOSHIRO256PLUS: Xoshiro256plus = Xoshiro256plus::default();
+}
+
+#[inline(always)]
+pub fn xoshiro256plus_seed() {
+    XOSHIRO256PLUS.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn xoshiro256plus_u64() -> u64 {
+    XOSHIRO256PLUS.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static XOSHIRO512PLUSPLUS: Xoshiro512plusplus = Xoshiro512plusplus::default();
+}
+
+#[inline(always)]
+pub fn xoshiro512plusplus_seed() {
+    XOSHIRO512PLUSPLUS.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn xoshiro512plusplus_u64() -> u64 {
+    XOSHIRO512PLUSPLUS.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static XOROSHIRO128PLUSPLUS: Xoroshiro128plusplus = Xoroshiro128plusplus::default();
+}
+
+#[inline(always)]
+pub fn xoroshiro128plusplus_seed() {
+    XOROSHIRO128PLUSPLUS.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn xoroshiro128plusplus_u64() -> u64 {
+    XOROSHIRO128PLUSPLUS.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static XOROSHIRO128STARSTAR: Xoroshiro128starstar = Xoroshiro128starstar::default();
+}
+
+#[inline(always)]
+pub fn xoroshiro128starstar_seed() {
+    XOROSHIRO128STARSTAR.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn xoroshiro128starstar_u64() -> u64 {
+    XOROSHIRO128STARSTAR.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static XOROSHIRO128PLUS: Xoroshiro128plus = Xoroshiro128plus::default();
+}
+
+#[inline(always)]
+pub fn xoroshiro128plus_seed() {
+    XOROSHIRO128PLUS.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn xoroshiro128plus_u64() -> u64 {
+    XOROSHIRO128PLUS.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static XOROSHIRO1024PLUSPLUS: Xoroshiro1024plusplus = Xoroshiro1024plusplus::default();
+}
+
+#[inline(always)]
+pub fn xoroshiro1024plusplus_seed() {
+    XOROSHIRO1024PLUSPLUS.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn xoroshiro1024plusplus_u64() -> u64 {
+    XOROSHIRO1024PLUSPLUS.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static ROMUQUAD: RomuQuad = RomuQuad::default();
+}
+
+#[inline(always)]
+pub fn romu_quad_seed() {
+    ROMUQUAD.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn romu_quad_u64() -> u64 {
+    ROMUQUAD.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static ROMUDUO: RomuDuo = RomuDuo::default();
+}
+
+#[inline(always)]
+pub fn romu_duo_seed() {
+    ROMUDUO.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn romu_duo_u64() -> u64 {
+    ROMUDUO.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static SFC64: Sfc64 = Sfc64::default();
+}
+
+#[inline(always)]
+pub fn sfc64_seed() {
+    SFC64.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn sfc64_u64() -> u64 {
+    SFC64.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static JSF64: Jsf64 = Jsf64::default();
+}
+
+#[inline(always)]
+pub fn jsf64_seed() {
+    JSF64.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn jsf64_u64() -> u64 {
+    JSF64.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static CHACHA8: ChaCha8 = ChaCha8::default();
+}
+
+#[inline(always)]
+pub fn chacha8_seed() {
+    CHACHA8.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn chacha8_u64() -> u64 {
+    CHACHA8.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static CHACHA12: ChaCha12 = ChaCha12::default();
+}
+
+#[inline(always)]
+pub fn chacha12_seed() {
+    CHACHA12.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn chacha12_u64() -> u64 {
+    CHACHA12.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static CHACHA20: ChaCha20 = ChaCha20::default();
+}
+
+#[inline(always)]
+pub fn chacha20_seed() {
+    CHACHA20.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn chacha20_u64() -> u64 {
+    CHACHA20.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static MT19937_64: Mt19937_64 = Mt19937_64::default();
+}
+
+#[inline(always)]
+pub fn mt19937_64_seed() {
+    MT19937_64.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn mt19937_64_u64() -> u64 {
+    MT19937_64.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static XORSHIFT128PLUS: Xorshift128plus = Xorshift128plus::default();
+}
+
+#[inline(always)]
+pub fn xorshift128plus_seed() {
+    XORSHIFT128PLUS.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn xorshift128plus_u64() -> u64 {
+    XORSHIFT128PLUS.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static MWC128: Mwc128 = Mwc128::default();
+}
+
+#[inline(always)]
+pub fn mwc128_seed() {
+    MWC128.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn mwc128_u64() -> u64 {
+    MWC128.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static MWC192: Mwc192 = Mwc192::default();
+}
+
+#[inline(always)]
+pub fn mwc192_seed() {
+    MWC192.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn mwc192_u64() -> u64 {
+    MWC192.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static MWC256: Mwc256 = Mwc256::default();
+}
+
+#[inline(always)]
+pub fn mwc256_seed() {
+    MWC256.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn mwc256_u64() -> u64 {
+    MWC256.with(|rng| rng.u64())
+}
+
+thread_local! {
+    static MWC128XXA32: Widen<Mwc128XXA32> = Widen::default();
+}
+
+#[inline(always)]
+pub fn mwc128xxa32_seed() {
+    MWC128XXA32.with(|rng| rng.seed())
+}
+
+#[inline(always)]
+pub fn mwc128xxa32_u64() -> u64 {
+    MWC128XXA32.with(|rng| rng.u64())
+}
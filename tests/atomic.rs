@@ -0,0 +1,105 @@
+use prng_benchmark::atomic::*;
+use prng_benchmark::*;
+
+const THREADS: usize = 8;
+const PER_THREAD: usize = 10_000;
+
+/// Draws `PER_THREAD` outputs on each of `THREADS` threads at once.
+fn draw_concurrently(rng: &(impl Random + Sync)) -> Vec<u64> {
+    std::thread::scope(|s| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| s.spawn(|| (0..PER_THREAD).map(|_| rng.u64()).collect::<Vec<_>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    })
+}
+
+/// Checks that the threads together drew exactly the first outputs of the
+/// single-threaded stream, so no output was lost or handed out twice.
+fn assert_shares_the_stream(shared: &(impl Random + Sync), reference: &impl Random) {
+    let mut outputs = draw_concurrently(shared);
+    let mut expected: Vec<u64> = (0..THREADS * PER_THREAD).map(|_| reference.u64()).collect();
+    outputs.sort_unstable();
+    expected.sort_unstable();
+    assert!(outputs == expected);
+    assert_eq!(shared.u64(), reference.u64());
+}
+
+#[test]
+fn atomic_generators_match_their_stream() {
+    for seed in [0, 1, 42, u64::MAX] {
+        let rng = AtomicWyrand::from_seed(seed);
+        let expected = Wyrand::from_seed(seed);
+        assert!((0..100).all(|_| rng.u64() == expected.u64()));
+
+        let rng = AtomicSplitmix64::from_seed(seed);
+        let expected = Splitmix64::from_seed(seed);
+        assert!((0..100).all(|_| rng.u64() == expected.u64()));
+
+        let rng = AtomicWyrand::default();
+        rng.seed_from_u64(seed);
+        assert_eq!(rng.u64(), Wyrand::from_seed(seed).u64());
+    }
+}
+
+#[test]
+fn atomic_generators_share_the_stream_between_threads() {
+    assert_shares_the_stream(&AtomicWyrand::from_seed(7), &Wyrand::from_seed(7));
+    assert_shares_the_stream(&AtomicSplitmix64::from_seed(7), &Splitmix64::from_seed(7));
+}
+
+#[test]
+fn shared_generators_share_the_stream_between_threads() {
+    let shared = Shared::new(Xoshiro256plusplus::from_seed(7));
+    assert_shares_the_stream(&shared, &Xoshiro256plusplus::from_seed(7));
+
+    let shared = Shared::new(ChaCha8::from_seed(7));
+    assert_shares_the_stream(&shared, &ChaCha8::from_seed(7));
+}
+
+#[test]
+fn shared_delegates_to_the_generator() {
+    let shared = Shared::new(Pcg64::from_seed(3));
+    let expected = Pcg64::from_seed(3);
+    assert_eq!(shared.u32(), expected.u32());
+
+    let mut bytes = [0; 13];
+    let mut expected_bytes = [0; 13];
+    shared.fill_bytes(&mut bytes);
+    expected.fill_bytes(&mut expected_bytes);
+    assert_eq!(bytes, expected_bytes);
+
+    shared.seed_from_u64(5);
+    expected.seed_from_u64(5);
+    assert_eq!(
+        shared.with(|rng| [rng.u64(), rng.u64()]),
+        [expected.u64(), expected.u64()]
+    );
+    assert_eq!(shared.into_inner().u64(), expected.u64());
+}
+
+#[test]
+fn shared_is_unlocked_after_a_panic() {
+    let shared = Shared::new(Wyrand::from_seed(1));
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        shared.with(|_| panic!("inside the lock"))
+    }));
+    assert!(result.is_err());
+    assert_eq!(shared.u64(), Wyrand::from_seed(1).u64());
+}
+
+static GLOBAL: Shared<Xoshiro256plusplus> = Shared::new(Xoshiro256plusplus::from_seed(42));
+static IDS: AtomicSplitmix64 = AtomicSplitmix64::from_seed(42);
+
+#[test]
+fn statics_can_be_used_from_any_thread() {
+    let outputs = draw_concurrently(&GLOBAL);
+    assert_eq!(outputs.len(), THREADS * PER_THREAD);
+    let mut ids = draw_concurrently(&IDS);
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids.len(), THREADS * PER_THREAD);
+}
@@ -0,0 +1,100 @@
+use std::cell::Cell;
+
+mod common;
+
+use common::assert_chi_square;
+use prng_benchmark::*;
+
+/// Replays a fixed list of outputs.
+struct Replay<const N: usize> {
+    values: [u64; N],
+    index: Cell<usize>,
+}
+
+impl<const N: usize> Random for Replay<N> {
+    fn u64(&self) -> u64 {
+        let index = self.index.get();
+        self.index.set(index + 1);
+        self.values[index]
+    }
+
+    fn seed(&self) {}
+}
+
+fn replay<const N: usize>(values: [u64; N]) -> BitBuffer<Replay<N>> {
+    BitBuffer::new(Replay {
+        values,
+        index: Cell::new(0),
+    })
+}
+
+#[test]
+fn bits_come_out_least_significant_first() {
+    let buffer = replay([0xFEDC_BA98_7654_3210, 0x0123_4567_89AB_CDEF]);
+    for nibble in 0..16 {
+        assert_eq!(buffer.bits(4), nibble);
+    }
+    assert!(buffer.bool());
+    assert!(buffer.bool());
+    assert!(buffer.bool());
+    assert!(buffer.bool());
+    assert!(!buffer.bool());
+    assert_eq!(buffer.rng().index.get(), 2);
+}
+
+#[test]
+fn leftover_bits_become_the_low_bits() {
+    let buffer = replay([u64::MAX, 0, 0x0123_4567_89AB_CDEF]);
+    assert_eq!(buffer.bits(60), (1 << 60) - 1);
+    // 4 bits from the first output, 6 from the second.
+    assert_eq!(buffer.bits(10), 0b00_0000_1111);
+    assert_eq!(buffer.bits(58), 0);
+    assert_eq!(buffer.bits(64), 0x0123_4567_89AB_CDEF);
+    assert_eq!(buffer.bits(0), 0);
+}
+
+#[test]
+fn whole_outputs_pass_through() {
+    let buffer = replay([1, 2, 3, 4]);
+    assert_eq!(buffer.bits(64), 1);
+    assert_eq!(buffer.bits(64), 2);
+    buffer.bool();
+    buffer.clear();
+    assert_eq!(buffer.bits(64), 4);
+}
+
+#[test]
+fn bounded_rejects_out_of_range_values() {
+    // 7 and 6 don't fit below 6, 5 does.
+    let buffer = replay([0b101_110_111]);
+    assert_eq!(buffer.bounded(6), 5);
+    assert_eq!(buffer.bounded(1), 0);
+    assert_eq!(buffer.rng().index.get(), 1);
+}
+
+#[test]
+#[should_panic(expected = "bound must not be 0")]
+fn bounded_rejects_a_zero_bound() {
+    replay([0]).bounded(0);
+}
+
+#[test]
+fn bool_and_bounded_are_uniform() {
+    let buffer = BitBuffer::new(Pcg64::default());
+    buffer.rng().seed_from_u64(0xB17);
+
+    let heads = (0..100_000).filter(|_| buffer.bool()).count() as u64;
+    assert_chi_square("bool", &[100_000 - heads, heads], &[0.5, 0.5]);
+
+    for bound in [3, 6, 10, 37] {
+        let mut observed = vec![0; bound as usize];
+        for _ in 0..100_000 {
+            observed[buffer.bounded(bound) as usize] += 1;
+        }
+        assert_chi_square(
+            &format!("bounded({bound})"),
+            &observed,
+            &vec![1.0 / bound as f64; bound as usize],
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use prng_benchmark::{ChaCha12, ChaCha20, ChaCha8, Random};
+
+fn keystream(rng: &impl Random, len: usize) -> Vec<u8> {
+    (0..len.div_ceil(8))
+        .flat_map(|_| rng.u64().to_le_bytes())
+        .take(len)
+        .collect()
+}
+
+fn rfc8439_key() -> [u8; 32] {
+    std::array::from_fn(|i| i as u8)
+}
+
+#[test]
+fn chacha20_rfc8439_block_function() {
+    // RFC 8439, section 2.3.2.
+    let rng = ChaCha20::from_rfc8439(rfc8439_key(), 1, [0, 0, 0, 9, 0, 0, 0, 0x4A, 0, 0, 0, 0]);
+
+    #[rustfmt::skip]
+    let expected = [
+        0x10, 0xF1, 0xE7, 0xE4, 0xD1, 0x3B, 0x59, 0x15, 0x50, 0x0F, 0xDD, 0x1F, 0xA3, 0x20, 0x71, 0xC4,
+        0xC7, 0xD1, 0xF4, 0xC7, 0x33, 0xC0, 0x68, 0x03, 0x04, 0x22, 0xAA, 0x9A, 0xC3, 0xD4, 0x6C, 0x4E,
+        0xD2, 0x82, 0x64, 0x46, 0x07, 0x9F, 0xAA, 0x09, 0x14, 0xC2, 0xD7, 0x05, 0xD9, 0x8B, 0x02, 0xA2,
+        0xB5, 0x12, 0x9C, 0xD1, 0xDE, 0x16, 0x4E, 0xB9, 0xCB, 0xD0, 0x83, 0xE8, 0xA2, 0x50, 0x3C, 0x4E,
+    ];
+    assert_eq!(keystream(&rng, 64), expected);
+}
+
+#[test]
+fn chacha20_rfc8439_encryption() {
+    // RFC 8439, section 2.4.2. Spans two blocks, so it also covers the counter increment.
+    let rng = ChaCha20::from_rfc8439(rfc8439_key(), 1, [0, 0, 0, 0, 0, 0, 0, 0x4A, 0, 0, 0, 0]);
+    let plaintext = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+
+    #[rustfmt::skip]
+    let expected = [
+        0x6E, 0x2E, 0x35, 0x9A, 0x25, 0x68, 0xF9, 0x80, 0x41, 0xBA, 0x07, 0x28, 0xDD, 0x0D, 0x69, 0x81,
+        0xE9, 0x7E, 0x7A, 0xEC, 0x1D, 0x43, 0x60, 0xC2, 0x0A, 0x27, 0xAF, 0xCC, 0xFD, 0x9F, 0xAE, 0x0B,
+        0xF9, 0x1B, 0x65, 0xC5, 0x52, 0x47, 0x33, 0xAB, 0x8F, 0x59, 0x3D, 0xAB, 0xCD, 0x62, 0xB3, 0x57,
+        0x16, 0x39, 0xD6, 0x24, 0xE6, 0x51, 0x52, 0xAB, 0x8F, 0x53, 0x0C, 0x35, 0x9F, 0x08, 0x61, 0xD8,
+        0x07, 0xCA, 0x0D, 0xBF, 0x50, 0x0D, 0x6A, 0x61, 0x56, 0xA3, 0x8E, 0x08, 0x8A, 0x22, 0xB6, 0x5E,
+        0x52, 0xBC, 0x51, 0x4D, 0x16, 0xCC, 0xF8, 0x06, 0x81, 0x8C, 0xE9, 0x1A, 0xB7, 0x79, 0x37, 0x36,
+        0x5A, 0xF9, 0x0B, 0xBF, 0x74, 0xA3, 0x5B, 0xE6, 0xB4, 0x0B, 0x8E, 0xED, 0xF2, 0x78, 0x5E, 0x42,
+        0x87, 0x4D,
+    ];
+    let ciphertext: Vec<u8> = keystream(&rng, plaintext.len())
+        .iter()
+        .zip(plaintext)
+        .map(|(k, p)| k ^ p)
+        .collect();
+    assert_eq!(ciphertext, expected);
+}
+
+#[test]
+fn chacha20_rfc8439_zero_key() {
+    // RFC 8439, appendix A.1, test vector #1.
+    let rng = ChaCha20::new([0; 32], 0);
+
+    #[rustfmt::skip]
+    let expected = [
+        0x76, 0xB8, 0xE0, 0xAD, 0xA0, 0xF1, 0x3D, 0x90, 0x40, 0x5D, 0x6A, 0xE5, 0x53, 0x86, 0xBD, 0x28,
+        0xBD, 0xD2, 0x19, 0xB8, 0xA0, 0x8D, 0xED, 0x1A, 0xA8, 0x36, 0xEF, 0xCC, 0x8B, 0x77, 0x0D, 0xC7,
+        0xDA, 0x41, 0x59, 0x7C, 0x51, 0x57, 0x48, 0x8D, 0x77, 0x24, 0xE0, 0x3F, 0xB8, 0xD8, 0x4A, 0x37,
+        0x6A, 0x43, 0xB8, 0xF4, 0x15, 0x18, 0xA1, 0x1C, 0xC3, 0x87, 0xB6, 0x69, 0xB2, 0xEE, 0x65, 0x86,
+    ];
+    assert_eq!(keystream(&rng, 64), expected);
+}
+
+#[test]
+fn chacha8_zero_key() {
+    // draft-strombergson-chacha-test-vectors, TC1 with a 256-bit key and 8 rounds.
+    let rng = ChaCha8::new([0; 32], 0);
+
+    #[rustfmt::skip]
+    let expected = [
+        0x3E, 0x00, 0xEF, 0x2F, 0x89, 0x5F, 0x40, 0xD6, 0x7F, 0x5B, 0xB8, 0xE8, 0x1F, 0x09, 0xA5, 0xA1,
+        0x2C, 0x84, 0x0E, 0xC3, 0xCE, 0x9A, 0x7F, 0x3B, 0x18, 0x1B, 0xE1, 0x88, 0xEF, 0x71, 0x1A, 0x1E,
+        0x98, 0x4C, 0xE1, 0x72, 0xB9, 0x21, 0x6F, 0x41, 0x9F, 0x44, 0x53, 0x67, 0x45, 0x6D, 0x56, 0x19,
+        0x31, 0x4A, 0x42, 0xA3, 0xDA, 0x86, 0xB0, 0x01, 0x38, 0x7B, 0xFD, 0xB8, 0x0E, 0x0C, 0xFE, 0x42,
+    ];
+    assert_eq!(keystream(&rng, 64), expected);
+}
+
+#[test]
+fn chacha12_zero_key() {
+    // draft-strombergson-chacha-test-vectors, TC1 with a 256-bit key and 12 rounds.
+    let rng = ChaCha12::new([0; 32], 0);
+
+    #[rustfmt::skip]
+    let expected = [
+        0x9B, 0xF4, 0x9A, 0x6A, 0x07, 0x55, 0xF9, 0x53, 0x81, 0x1F, 0xCE, 0x12, 0x5F, 0x26, 0x83, 0xD5,
+        0x04, 0x29, 0xC3, 0xBB, 0x49, 0xE0, 0x74, 0x14, 0x7E, 0x00, 0x89, 0xA5, 0x2E, 0xAE, 0x15, 0x5F,
+        0x05, 0x64, 0xF8, 0x79, 0xD2, 0x7A, 0xE3, 0xC0, 0x2C, 0xE8, 0x28, 0x34, 0xAC, 0xFA, 0x8C, 0x79,
+        0x3A, 0x62, 0x9F, 0x2C, 0xA0, 0xDE, 0x69, 0x19, 0x61, 0x0B, 0xE8, 0x2F, 0x41, 0x13, 0x26, 0xBE,
+    ];
+    assert_eq!(keystream(&rng, 64), expected);
+}
+
+#[test]
+fn odd_index_is_rounded_down() {
+    let rng = ChaCha8::new([0; 32], 0);
+    let expected = ChaCha8::new([0; 32], 0);
+    rng.u64();
+    rng.index.set(15);
+    for _ in 0..7 {
+        expected.u64();
+    }
+    assert_eq!(rng.u64(), expected.u64());
+    assert_eq!(rng.u64(), expected.u64());
+}
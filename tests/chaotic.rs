@@ -0,0 +1,36 @@
+use prng_benchmark::{Jsf64, Random, Sfc64};
+
+// The expected values were produced with the reference implementations:
+// `raninit` / `ranval` by Bob Jenkins and `sfc64` from PractRand.
+
+#[test]
+fn jsf64_reference() {
+    let rng = Jsf64::new(42);
+    let expected: [u64; 6] = [
+        11921485425870369842,
+        6950967119895308506,
+        3738120138616583258,
+        12954590915796698081,
+        16833194306754848519,
+        6352690194663110999,
+    ];
+    for x in expected {
+        assert_eq!(rng.u64(), x);
+    }
+}
+
+#[test]
+fn sfc64_reference() {
+    let rng = Sfc64::new(42);
+    let expected: [u64; 6] = [
+        9593766767639209231,
+        7993095875549472148,
+        7611607860230059198,
+        11103719255792862824,
+        3025130052202411035,
+        13159439222248462322,
+    ];
+    for x in expected {
+        assert_eq!(rng.u64(), x);
+    }
+}
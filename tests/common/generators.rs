@@ -0,0 +1,72 @@
+//! The list of generators shared by the test suites and the benchmarks.
+
+/// Runs `$body` once for every generator, with `$id` bound to its name in the
+/// benchmarks and `tests/fingerprints.txt`, `$r` to its type and the optional
+/// `$from_seed` to its `from_seed()`. This is the one list of generators that
+/// the test suites and benchmark groups go through, so a new generator only
+/// has to be added here.
+#[allow(unused_macros)]
+macro_rules! for_each_generator {
+    (|$id:pat, $r:ident| $body:expr) => {
+        for_each_generator!(|$id, $r, _| $body)
+    };
+    (|$id:pat, $r:ident, $from_seed:pat_param| $body:expr) => {
+        for_each_generator!(
+            @each $id, $r, $from_seed, $body,
+            "chacha8" ChaCha8 = ChaCha8::from_seed,
+            "chacha12" ChaCha12 = ChaCha12::from_seed,
+            "chacha20" ChaCha20 = ChaCha20::from_seed,
+            "jsf64" Jsf64 = Jsf64::from_seed,
+            "lehmer64" Lehmer64 = Lehmer64::from_seed,
+            "mt19937_64" Mt19937_64 = Mt19937_64::from_seed,
+            "mwc128" Mwc128 = Mwc128::from_seed,
+            "mwc192" Mwc192 = Mwc192::from_seed,
+            "mwc256" Mwc256 = Mwc256::from_seed,
+            "mwc256xxa64" Mwc256XXA64 = Mwc256XXA64::from_seed,
+            "pcg64" Pcg64 = Pcg64::from_seed,
+            "pcg64fast" Pcg64Fast = Pcg64Fast::from_seed,
+            "pcg64dxsm" Pcg64Dxsm = Pcg64Dxsm::from_seed,
+            "pcg64dxsmfast" Pcg64DxsmFast = Pcg64DxsmFast::from_seed,
+            "romu_jr" RomuJr = RomuJr::from_seed,
+            "romu_trio" RomuTrio = RomuTrio::from_seed,
+            "romu_quad" RomuQuad = RomuQuad::from_seed,
+            "romu_duo" RomuDuo = RomuDuo::from_seed,
+            "sfc64" Sfc64 = Sfc64::from_seed,
+            "splitmix64" Splitmix64 = Splitmix64::from_seed,
+            "tylo64" Tylo64 = Tylo64::from_seed,
+            "wyrand" Wyrand = Wyrand::from_seed,
+            "xorshift128+" Xorshift128plus = Xorshift128plus::from_seed,
+            "xoshiro256++" Xoshiro256plusplus = Xoshiro256plusplus::from_seed,
+            "xoshiro256**" Xoshiro256starstar = Xoshiro256starstar::from_seed,
+            "xoshiro256+" Xoshiro256plus = Xoshiro256plus::from_seed,
+            "xoshiro512++" Xoshiro512plusplus = Xoshiro512plusplus::from_seed,
+            "xoroshiro128++" Xoroshiro128plusplus = Xoroshiro128plusplus::from_seed,
+            "xoroshiro128**" Xoroshiro128starstar = Xoroshiro128starstar::from_seed,
+            "xoroshiro128+" Xoroshiro128plus = Xoroshiro128plus::from_seed,
+            "xoroshiro1024++" Xoroshiro1024plusplus = Xoroshiro1024plusplus::from_seed,
+            "mwc128xxa32" Widen<Mwc128XXA32> = |seed| Widen(Mwc128XXA32::from_seed(seed)),
+            "pcg32" Widen<Pcg32> = |seed| Widen(Pcg32::from_seed(seed)),
+            "romu_trio32" Widen<RomuTrio32> = |seed| Widen(RomuTrio32::from_seed(seed)),
+            "sfc32" Widen<Sfc32> = |seed| Widen(Sfc32::from_seed(seed)),
+            "xoshiro128++" Widen<Xoshiro128plusplus> = |seed| Widen(Xoshiro128plusplus::from_seed(seed)),
+            "atomic_wyrand" atomic::AtomicWyrand = atomic::AtomicWyrand::from_seed,
+            "atomic_splitmix64" atomic::AtomicSplitmix64 = atomic::AtomicSplitmix64::from_seed,
+        )
+    };
+    (@each $id:pat, $r:ident, $from_seed:pat, $body:expr,
+        $($name:literal $($path:ident)::+ $(<$inner:ident>)? = $constructor:expr,)*) => {
+        $({
+            type $r = prng_benchmark::$($path)::+ $(<prng_benchmark::$inner>)?;
+            let $id: &str = $name;
+            let $from_seed = {
+                #[allow(unused_imports)]
+                use prng_benchmark::*;
+                $constructor
+            };
+            $body;
+        })*
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use for_each_generator;
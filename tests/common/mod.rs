@@ -0,0 +1,100 @@
+//! Helpers shared by the statistical tests.
+
+#![allow(dead_code)]
+
+use std::f64::consts::PI;
+
+mod generators;
+
+#[allow(unused_imports)]
+pub(crate) use generators::for_each_generator;
+
+/// Lanczos approximation with g = 7.
+pub fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.5203681218851,
+        -1259.1392167224028,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507343278686905,
+        -0.13857109526572012,
+        9.984_369_578_019_572e-6,
+        1.5056327351493116e-7,
+    ];
+
+    if x < 0.5 {
+        return (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let sum = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |sum, (i, c)| {
+            sum + c / (x + i as f64 + 1.0)
+        });
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// Returns the chi-square statistic and the degrees of freedom of the observed
+/// counts against the expected probabilities. Neighbouring cells are merged
+/// until each expects at least 5 hits.
+pub fn chi_square(observed: &[u64], probabilities: &[f64]) -> (f64, usize) {
+    let n = observed.iter().sum::<u64>() as f64;
+
+    let mut cells = Vec::new();
+    let (mut o, mut e) = (0.0, 0.0);
+    for (&observed, &p) in observed.iter().zip(probabilities) {
+        o += observed as f64;
+        e += p * n;
+        if e >= 5.0 {
+            cells.push((o, e));
+            (o, e) = (0.0, 0.0);
+        }
+    }
+    match cells.last_mut() {
+        Some(last) => {
+            last.0 += o;
+            last.1 += e;
+        }
+        None => cells.push((o, e)),
+    }
+
+    let statistic = cells.iter().map(|(o, e)| (o - e) * (o - e) / e).sum();
+    (statistic, cells.len().saturating_sub(1))
+}
+
+/// The standard normal quantile for alpha = 0.001.
+pub const Z_0_001: f64 = 3.090232;
+
+/// The standard normal quantile for alpha = 0.00001, for tests that repeat a
+/// check for every generator and would otherwise fail spuriously.
+pub const Z_0_00001: f64 = 4.264891;
+
+/// The critical value of the chi-square distribution for the upper quantile
+/// `z` of the standard normal distribution, using the Wilson-Hilferty
+/// approximation.
+pub fn chi_square_critical_value(degrees_of_freedom: usize, z: f64) -> f64 {
+    let k = degrees_of_freedom as f64;
+    let h = 2.0 / (9.0 * k);
+    k * (1.0 - h + z * h.sqrt()).powi(3)
+}
+
+/// Asserts that the observed counts fit the expected probabilities with
+/// alpha = 0.001.
+pub fn assert_chi_square(name: &str, observed: &[u64], probabilities: &[f64]) {
+    assert_chi_square_at(name, observed, probabilities, Z_0_001)
+}
+
+/// Asserts that the observed counts fit the expected probabilities at the
+/// significance level given by the normal quantile `z`.
+pub fn assert_chi_square_at(name: &str, observed: &[u64], probabilities: &[f64], z: f64) {
+    let (statistic, degrees_of_freedom) = chi_square(observed, probabilities);
+    let critical = chi_square_critical_value(degrees_of_freedom, z);
+    assert!(
+        statistic < critical,
+        "{name}: chi-square {statistic} >= {critical} with {degrees_of_freedom} degrees of freedom"
+    );
+}
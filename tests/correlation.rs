@@ -0,0 +1,111 @@
+use std::cell::Cell;
+
+mod common;
+
+use common::for_each_generator;
+use prng_benchmark::correlation::{self, Relation, SUSPICIOUS_Z};
+use prng_benchmark::*;
+
+const STREAMS: usize = 64;
+const BASE_SEED: u64 = 0x1234_5678_9ABC_DEF0;
+
+fn analyze<R: Random>(streams: &[R], rounds: usize) -> correlation::Report {
+    correlation::analyze(&correlation::interleave(streams, rounds), streams.len())
+}
+
+/// `seed_from_u64()` expands every seed with SplitMix64, so even related seeds
+/// must give unrelated streams.
+fn assert_decorrelated<R: SeedableRandom + Default>() {
+    for relation in [Relation::Sequential, Relation::BitFlip] {
+        let streams: Vec<R> = correlation::related_seeds(BASE_SEED, relation, STREAMS)
+            .map(|seed| {
+                let rng = R::default();
+                rng.seed_from_u64(seed);
+                rng
+            })
+            .collect();
+        let report = analyze(&streams, 256);
+        assert!(
+            !report.is_suspicious(),
+            "{} with {relation:?} seeds: {report:?}",
+            std::any::type_name::<R>()
+        );
+    }
+}
+
+#[test]
+fn seed_from_u64_decorrelates_related_seeds() {
+    for_each_generator!(|_, R| assert_decorrelated::<R>());
+}
+
+#[test]
+fn counter_states_are_flagged_for_wyrand_only() {
+    // Both use the seed as a counter, but only SplitMix64 mixes it thoroughly.
+    let splitmix: Vec<Splitmix64> =
+        correlation::related_seeds(BASE_SEED, Relation::Sequential, STREAMS)
+            .map(|seed| Splitmix64 {
+                state: Cell::new(seed),
+            })
+            .collect();
+    let report = analyze(&splitmix, 4096);
+    assert!(!report.is_suspicious(), "{report:?}");
+
+    for relation in [Relation::Sequential, Relation::BitFlip] {
+        let wyrand: Vec<Wyrand> = correlation::related_seeds(BASE_SEED, relation, STREAMS)
+            .map(|seed| Wyrand {
+                state: Cell::new(seed),
+            })
+            .collect();
+        let report = analyze(&wyrand, 4096);
+        assert!(report.is_suspicious(), "{relation:?}: {report:?}");
+    }
+}
+
+#[test]
+fn nearby_pcg_increments_are_flagged() {
+    // The same state with increments that differ by a little starts out
+    // correlated, until the streams drift apart.
+    for relation in [Relation::Sequential, Relation::BitFlip] {
+        let pcg: Vec<Pcg64> = correlation::related_seeds(BASE_SEED, relation, STREAMS)
+            .map(|seed| Pcg64 {
+                state: Cell::new(0x0123_4567_89AB_CDEF),
+                inc: Cell::new(((seed as u128) << 1) | 1),
+            })
+            .collect();
+        let report = analyze(&pcg, 16);
+        assert!(report.is_suspicious(), "{relation:?}: {report:?}");
+    }
+}
+
+#[test]
+fn identical_streams_agree_completely() {
+    // Seeds that only differ in bit 0 end up with the same state, since
+    // `seed_from()` forces the words to be odd.
+    let streams: Vec<Xoshiro256plusplus> =
+        correlation::related_seeds(BASE_SEED, Relation::Sequential, 2)
+            .map(|seed| {
+                let rng = Xoshiro256plusplus::default();
+                let mut words = [seed, 1, 2, 3].into_iter();
+                rng.seed_from(|| words.next().unwrap());
+                rng
+            })
+            .collect();
+
+    let report = analyze(&streams, 100);
+    assert_eq!(report.worst_pair, 0);
+    assert_eq!(report.worst_agreement_z, (6400.0f64).sqrt());
+    assert!(report.worst_agreement_z > SUSPICIOUS_Z);
+    assert!(report.xor_z() > SUSPICIOUS_Z);
+}
+
+#[test]
+fn related_seeds_follow_the_relation() {
+    assert!(correlation::related_seeds(u64::MAX, Relation::Sequential, 3).eq([u64::MAX, 0, 1]));
+    assert!(correlation::related_seeds(0, Relation::BitFlip, 66).eq((0..66).map(|i| 1 << (i % 64))));
+}
+
+#[test]
+#[should_panic(expected = "at least two streams")]
+fn analyze_needs_pairs() {
+    correlation::analyze(&[1, 2, 3], 1);
+}
@@ -0,0 +1,241 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::{assert_chi_square, ln_gamma};
+use prng_benchmark::distributions::*;
+use prng_benchmark::{ChaCha8, Pcg64Dxsm, Random, SeedableRandom, Xoshiro256plusplus};
+
+const SAMPLES: usize = 100_000;
+
+fn ln_choose(n: u64, k: u64) -> f64 {
+    ln_gamma(n as f64 + 1.0) - ln_gamma(k as f64 + 1.0) - ln_gamma((n - k) as f64 + 1.0)
+}
+
+/// Samples with several generators and compares the histogram with `pmf`
+/// over `0..=max`, where the last cell also collects everything above.
+fn assert_fits<D: Distribution<u64>>(
+    name: &str,
+    distribution: &D,
+    max: u64,
+    pmf: impl Fn(u64) -> f64,
+) {
+    fn check<R: SeedableRandom, D: Distribution<u64>>(
+        name: &str,
+        rng: R,
+        distribution: &D,
+        max: u64,
+        pmf: &[f64],
+    ) {
+        rng.seed_from_u64(0xD15C2E7E);
+        let mut observed = vec![0; max as usize + 1];
+        for _ in 0..SAMPLES {
+            observed[distribution.sample(&rng).min(max) as usize] += 1;
+        }
+        assert_chi_square(
+            &format!("{name} with {}", std::any::type_name::<R>()),
+            &observed,
+            pmf,
+        );
+    }
+
+    let mut probabilities: Vec<f64> = (0..=max).map(&pmf).collect();
+    let tail = 1.0 - probabilities.iter().sum::<f64>();
+    *probabilities.last_mut().unwrap() += tail.max(0.0);
+
+    check(
+        name,
+        Xoshiro256plusplus::default(),
+        distribution,
+        max,
+        &probabilities,
+    );
+    check(
+        name,
+        Pcg64Dxsm::default(),
+        distribution,
+        max,
+        &probabilities,
+    );
+    check(name, ChaCha8::default(), distribution, max, &probabilities);
+}
+
+#[test]
+fn bernoulli_fits() {
+    let rng = Xoshiro256plusplus::default();
+    rng.seed_from_u64(7);
+
+    for (bernoulli, p) in [
+        (Bernoulli::new(0.3).unwrap(), 0.3),
+        (Bernoulli::new(0.999).unwrap(), 0.999),
+        (Bernoulli::from_ratio(1, 3).unwrap(), 1.0 / 3.0),
+    ] {
+        let successes = (0..SAMPLES).filter(|_| bernoulli.sample(&rng)).count() as u64;
+        assert_chi_square(
+            "Bernoulli",
+            &[SAMPLES as u64 - successes, successes],
+            &[1.0 - p, p],
+        );
+    }
+}
+
+#[test]
+fn bernoulli_extremes_are_exact() {
+    let rng = Xoshiro256plusplus::default();
+    let never = Bernoulli::new(0.0).unwrap();
+    let always = Bernoulli::new(1.0).unwrap();
+    let always_ratio = Bernoulli::from_ratio(5, 5).unwrap();
+    for _ in 0..10_000 {
+        assert!(!never.sample(&rng));
+        assert!(always.sample(&rng));
+        assert!(always_ratio.sample(&rng));
+    }
+}
+
+#[test]
+fn bernoulli_compares_integers() {
+    struct Fixed(u64);
+
+    impl Random for Fixed {
+        fn u64(&self) -> u64 {
+            self.0
+        }
+
+        fn seed(&self) {}
+    }
+
+    let half = Bernoulli::new(0.5).unwrap();
+    assert!(half.sample(&Fixed((1 << 63) - 1)));
+    assert!(!half.sample(&Fixed(1 << 63)));
+
+    let third = Bernoulli::from_ratio(1, 3).unwrap();
+    assert!(third.sample(&Fixed(u64::MAX / 3 - 1)));
+    assert!(!third.sample(&Fixed(u64::MAX / 3)));
+}
+
+#[test]
+fn binomial_fits() {
+    // Covers inversion, BTPE and both with p > 0.5.
+    for (n, p) in [(20, 0.3), (15, 0.8), (1000, 0.4), (200, 0.93), (50, 0.5)] {
+        let binomial = Binomial::new(n, p).unwrap();
+        assert_fits("Binomial", &binomial, n, |k| {
+            (ln_choose(n, k) + k as f64 * p.ln() + (n - k) as f64 * (1.0 - p).ln()).exp()
+        });
+    }
+}
+
+#[test]
+fn binomial_degenerate_cases() {
+    let rng = Xoshiro256plusplus::default();
+    assert_eq!(Binomial::new(10, 0.0).unwrap().sample(&rng), 0);
+    assert_eq!(Binomial::new(10, 1.0).unwrap().sample(&rng), 10);
+    assert_eq!(Binomial::new(0, 0.5).unwrap().sample(&rng), 0);
+}
+
+#[test]
+fn poisson_fits() {
+    // Covers the multiplication method and PTRS.
+    for lambda in [0.5, 3.5, 10.0, 47.3, 1000.0] {
+        let poisson = Poisson::new(lambda).unwrap();
+        let max = (lambda + 8.0 * lambda.sqrt() + 10.0) as u64;
+        assert_fits("Poisson", &poisson, max, |k| {
+            (k as f64 * f64::ln(lambda) - lambda - ln_gamma(k as f64 + 1.0)).exp()
+        });
+    }
+}
+
+#[test]
+fn geometric_fits() {
+    for p in [0.9, 0.2, 0.01] {
+        let geometric = Geometric::new(p).unwrap();
+        let max = (20.0 / p) as u64;
+        assert_fits("Geometric", &geometric, max, |k| {
+            (1.0 - p).powi(k as i32) * p
+        });
+    }
+}
+
+#[test]
+fn hypergeometric_fits() {
+    // Covers inversion and H2PE.
+    for (total, successes, draws) in [
+        (50, 20, 10),
+        (10_000, 4_000, 500),
+        (100, 95, 30),
+        (30, 10, 25),
+        (10_000, 4_000, 2_000),
+        (1_000_000, 999_000, 500_000),
+    ] {
+        let hypergeometric = Hypergeometric::new(total, successes, draws).unwrap();
+        assert_fits("Hypergeometric", &hypergeometric, draws, |k| {
+            if k > successes || draws - k > total - successes {
+                return 0.0;
+            }
+            (ln_choose(successes, k) + ln_choose(total - successes, draws - k)
+                - ln_choose(total, draws))
+            .exp()
+        });
+    }
+}
+
+#[test]
+fn hypergeometric_handles_huge_populations() {
+    // ln_gamma differences lose all digits at this size, which used to leave
+    // the probabilities summing to far less than 1 and the sampler retrying forever.
+    let total = 1 << 50;
+    let hypergeometric = Hypergeometric::new(total, total / 2, 100_000).unwrap();
+    let rng = Xoshiro256plusplus::default();
+    rng.seed_from_u64(0x4E6);
+
+    let samples: Vec<f64> = (0..SAMPLES)
+        .map(|_| hypergeometric.sample(&rng) as f64)
+        .collect();
+    let mean = samples.iter().sum::<f64>() / SAMPLES as f64;
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / SAMPLES as f64;
+    // Practically Binomial(100_000, 0.5), with mean 50_000 and variance 25_000.
+    assert!((mean - 50_000.0).abs() < 5.0, "{mean}");
+    assert!((variance / 25_000.0 - 1.0).abs() < 0.05, "{variance}");
+}
+
+#[test]
+fn hypergeometric_samples_huge_variances_quickly() {
+    // Inversion would take about 2.5e8 steps per sample here.
+    let (total, half) = (1_000_000_000_000_000_000u64, 500_000_000_000_000_000);
+    let hypergeometric = Hypergeometric::new(total, half, half).unwrap();
+    let rng = Xoshiro256plusplus::default();
+    rng.seed_from_u64(0x1E18);
+
+    let start = Instant::now();
+    let deviations: Vec<f64> = (0..SAMPLES)
+        .map(|_| (hypergeometric.sample(&rng) as i64 - total as i64 / 4) as f64)
+        .collect();
+    let elapsed = start.elapsed();
+    assert!(elapsed < Duration::from_secs(5), "{elapsed:?}");
+
+    let mean = deviations.iter().sum::<f64>() / SAMPLES as f64;
+    let variance = deviations.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / SAMPLES as f64;
+    // The variance is total / 16, so the mean is within 5 standard errors.
+    let expected_variance = total as f64 / 16.0;
+    assert!(
+        mean.abs() < 5.0 * (expected_variance / SAMPLES as f64).sqrt(),
+        "{mean}"
+    );
+    assert!(
+        (variance / expected_variance - 1.0).abs() < 0.05,
+        "{variance}"
+    );
+}
+
+#[test]
+fn invalid_parameters_are_rejected() {
+    assert!(Bernoulli::new(1.5).is_none());
+    assert!(Bernoulli::new(f64::NAN).is_none());
+    assert!(Bernoulli::from_ratio(3, 2).is_none());
+    assert!(Bernoulli::from_ratio(0, 0).is_none());
+    assert!(Binomial::new(10, -0.1).is_none());
+    assert!(Poisson::new(0.0).is_none());
+    assert!(Poisson::new(f64::INFINITY).is_none());
+    assert!(Geometric::new(0.0).is_none());
+    assert!(Hypergeometric::new(10, 11, 5).is_none());
+    assert!(Hypergeometric::new(10, 5, 11).is_none());
+}
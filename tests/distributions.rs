@@ -0,0 +1,261 @@
+use std::f64::consts::{PI, SQRT_2};
+
+mod common;
+
+use common::ln_gamma;
+use prng_benchmark::distributions::*;
+use prng_benchmark::{ChaCha8, Pcg64Dxsm, SeedableRandom, Xoshiro256plusplus};
+
+const SAMPLES: usize = 20_000;
+
+/// The critical value of the Kolmogorov-Smirnov statistic for alpha = 0.001.
+fn ks_critical_value(n: usize) -> f64 {
+    1.949 / (n as f64).sqrt()
+}
+
+fn ks_statistic(mut samples: Vec<f64>, cdf: impl Fn(f64) -> f64) -> f64 {
+    samples.sort_by(f64::total_cmp);
+    let n = samples.len() as f64;
+    samples
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| {
+            let f = cdf(x);
+            (f - i as f64 / n).max((i + 1) as f64 / n - f)
+        })
+        .fold(0.0, f64::max)
+}
+
+fn assert_fits<D: Distribution<f64>>(name: &str, distribution: &D, cdf: impl Fn(f64) -> f64) {
+    fn check<R: SeedableRandom, D: Distribution<f64>>(
+        name: &str,
+        rng: R,
+        distribution: &D,
+        cdf: &impl Fn(f64) -> f64,
+    ) {
+        rng.seed_from_u64(0x5EED);
+        let samples: Vec<f64> = (0..SAMPLES).map(|_| distribution.sample(&rng)).collect();
+        let d = ks_statistic(samples, cdf);
+        assert!(
+            d < ks_critical_value(SAMPLES),
+            "{name} with {}: D = {d}",
+            std::any::type_name::<R>()
+        );
+    }
+
+    check(name, Xoshiro256plusplus::default(), distribution, &cdf);
+    check(name, Pcg64Dxsm::default(), distribution, &cdf);
+    check(name, ChaCha8::default(), distribution, &cdf);
+}
+
+/// Numerical Recipes' `erfcc`, with a fractional error below 1.2e-7.
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let ans = t
+        * (-z * z - 1.26551223
+            + t * (1.00002368
+                + t * (0.37409196
+                    + t * (0.09678418
+                        + t * (-0.18628806
+                            + t * (0.27886807
+                                + t * (-1.13520398
+                                    + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277)))))))))
+            .exp();
+    if x >= 0.0 {
+        ans
+    } else {
+        2.0 - ans
+    }
+}
+
+fn normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / SQRT_2)
+}
+
+/// The regularized lower incomplete gamma function P(a, x).
+fn gamma_p(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+
+    let prefix = (-x + a * x.ln() - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..1000 {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term < sum * 1e-15 {
+                break;
+            }
+        }
+        sum * prefix
+    } else {
+        1.0 - continued_fraction(|n| (-(n * (n - a)), 2.0 * n + 1.0 + x - a), x + 1.0 - a) * prefix
+    }
+}
+
+/// The regularized incomplete beta function I_x(a, b).
+fn beta_i(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    if x > (a + 1.0) / (a + b + 2.0) {
+        return 1.0 - beta_i(b, a, 1.0 - x);
+    }
+
+    let prefix =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    let cf = continued_fraction(
+        |n| {
+            let m = (n / 2.0).floor();
+            let numerator = if n as u64 % 2 == 1 {
+                -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0))
+            } else {
+                m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m))
+            };
+            (numerator, 1.0)
+        },
+        1.0,
+    );
+    prefix * cf / a
+}
+
+/// Evaluates `1 / (b0 + a1 / (b1 + a2 / (b2 + ...)))` with the modified Lentz method.
+fn continued_fraction(terms: impl Fn(f64) -> (f64, f64), b0: f64) -> f64 {
+    const TINY: f64 = 1e-300;
+
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b0;
+    let mut h = d;
+    for n in 1..1000 {
+        let (a, b) = terms(n as f64);
+        d = b + a * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = b + a / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < 1e-15 {
+            break;
+        }
+    }
+    h
+}
+
+#[test]
+fn reference_cdfs() {
+    assert!((normal_cdf(1.96) - 0.9750021048517795).abs() < 1e-6);
+    assert!((ln_gamma(10.0) - 362880f64.ln()).abs() < 1e-12);
+    assert!((gamma_p(3.0, 2.0) - 0.3233235838169366).abs() < 1e-12);
+    assert!((gamma_p(0.5, 4.0) - 0.9953222650189527).abs() < 1e-12);
+    assert!((beta_i(2.0, 3.0, 0.4) - 0.5248).abs() < 1e-12);
+    assert!((beta_i(0.5, 0.5, 0.25) - 1.0 / 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn standard_normal_fits() {
+    assert_fits("StandardNormal", &StandardNormal, normal_cdf);
+}
+
+#[test]
+fn normal_fits() {
+    let normal = Normal::new(-3.0, 2.5).unwrap();
+    assert_fits("Normal", &normal, |x| normal_cdf((x + 3.0) / 2.5));
+}
+
+#[test]
+fn normal_tail_is_sampled() {
+    let rng = Xoshiro256plusplus::default();
+    rng.seed_from_u64(1);
+
+    let n = 2_000_000;
+    let tail = (0..n)
+        .filter(|_| StandardNormal.sample(&rng).abs() > 3.654152885361009)
+        .count() as f64;
+    let expected = n as f64 * 2.0 * (1.0 - normal_cdf(3.654152885361009));
+    assert!(
+        (tail - expected).abs() < 5.0 * expected.sqrt(),
+        "{tail} vs {expected}"
+    );
+}
+
+#[test]
+fn exponential_fits() {
+    assert_fits("Exp1", &Exp1, |x| 1.0 - (-x).exp());
+
+    let exp = Exp::new(0.25).unwrap();
+    assert_fits("Exp", &exp, |x| 1.0 - (-0.25 * x).exp());
+}
+
+#[test]
+fn gamma_fits() {
+    for (shape, scale) in [(0.3, 1.0), (1.0, 2.0), (2.5, 0.5), (20.0, 3.0)] {
+        let gamma = Gamma::new(shape, scale).unwrap();
+        assert_fits("Gamma", &gamma, |x| gamma_p(shape, x / scale));
+    }
+}
+
+#[test]
+fn beta_fits() {
+    for (alpha, beta) in [(0.5, 0.5), (0.2, 0.7), (2.0, 3.0), (1.0, 8.0)] {
+        let distribution = Beta::new(alpha, beta).unwrap();
+        assert_fits("Beta", &distribution, |x| beta_i(alpha, beta, x));
+    }
+}
+
+#[test]
+fn beta_with_tiny_shapes_stays_in_unit_interval() {
+    let rng = Xoshiro256plusplus::default();
+    rng.seed_from_u64(0xB7A);
+    for (alpha, beta) in [(0.001, 0.001), (1e-5, 0.5), (0.9, 1e-4)] {
+        let distribution = Beta::new(alpha, beta).unwrap();
+        let samples: Vec<f64> = (0..SAMPLES).map(|_| distribution.sample(&rng)).collect();
+        assert!(
+            samples.iter().all(|x| (0.0..=1.0).contains(x)),
+            "Beta({alpha}, {beta})"
+        );
+        // Almost all the mass sits at the ends, in proportion to the shapes.
+        let ones = samples.iter().filter(|&&x| x > 0.5).count() as f64 / SAMPLES as f64;
+        let expected = alpha / (alpha + beta);
+        assert!(
+            (ones - expected).abs() < 0.02,
+            "Beta({alpha}, {beta}): {ones}"
+        );
+    }
+}
+
+#[test]
+fn log_normal_fits() {
+    let log_normal = LogNormal::new(0.5, 0.75).unwrap();
+    assert_fits("LogNormal", &log_normal, |x| {
+        normal_cdf((x.ln() - 0.5) / 0.75)
+    });
+}
+
+#[test]
+fn cauchy_fits() {
+    let cauchy = Cauchy::new(1.0, 2.0).unwrap();
+    assert_fits("Cauchy", &cauchy, |x| 0.5 + ((x - 1.0) / 2.0).atan() / PI);
+}
+
+#[test]
+fn invalid_parameters_are_rejected() {
+    assert!(Normal::new(0.0, -1.0).is_none());
+    assert!(Normal::new(f64::NAN, 1.0).is_none());
+    assert!(Exp::new(0.0).is_none());
+    assert!(Gamma::new(0.0, 1.0).is_none());
+    assert!(Gamma::new(1.0, f64::INFINITY).is_none());
+    assert!(Beta::new(1.0, -2.0).is_none());
+    assert!(LogNormal::new(0.0, f64::NAN).is_none());
+    assert!(Cauchy::new(0.0, 0.0).is_none());
+}
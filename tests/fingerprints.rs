@@ -0,0 +1,83 @@
+//! Catches any change to the output of a generator.
+//!
+//! Hashes the first MiB of `u64()` and of `fill_bytes()` for a fixed seed and
+//! compares it against `tests/fingerprints.txt`. After a deliberate change to
+//! a stream, regenerate the file with
+//!
+//! ```sh
+//! UPDATE_FINGERPRINTS=1 cargo test --test fingerprints
+//! ```
+//!
+//! and commit it together with the change. `fill_bytes()` writes native-endian
+//! words, so the fingerprints only hold on little-endian targets.
+
+use std::fs;
+use std::path::PathBuf;
+
+mod common;
+
+use common::for_each_generator;
+use prng_benchmark::*;
+
+const SEED: u64 = 0x5EED_F1A6_E2B1_0C75;
+const BYTES: usize = 1 << 20;
+
+/// 64-bit FNV-1a, which is good enough to notice a changed stream and stays
+/// the same across Rust versions, unlike `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xCBF29CE484222325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001B3)
+    })
+}
+
+fn fingerprint<R: SeedableRandom + Default>(id: &str) -> String {
+    let rng = R::default();
+    rng.seed_from_u64(SEED);
+    let words: Vec<u8> = (0..BYTES / 8)
+        .flat_map(|_| rng.u64().to_le_bytes())
+        .collect();
+
+    rng.seed_from_u64(SEED);
+    let mut bytes = vec![0; BYTES];
+    rng.fill_bytes(&mut bytes);
+
+    format!(
+        "{id} u64 {:016x} fill_bytes {:016x}",
+        fnv1a(&words),
+        fnv1a(&bytes)
+    )
+}
+
+fn fingerprints() -> Vec<String> {
+    let mut fingerprints = Vec::new();
+    for_each_generator!(|id, R| fingerprints.push(fingerprint::<R>(id)));
+    fingerprints
+}
+
+#[test]
+#[cfg_attr(target_endian = "big", ignore)]
+fn streams_match_fingerprints() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fingerprints.txt");
+    let actual = fingerprints();
+
+    if std::env::var_os("UPDATE_FINGERPRINTS").is_some() {
+        fs::write(&path, actual.join("\n") + "\n").unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap();
+    let expected: Vec<&str> = expected.lines().collect();
+    let changed: Vec<&String> = actual
+        .iter()
+        .filter(|line| !expected.contains(&line.as_str()))
+        .collect();
+    assert!(
+        changed.is_empty() && expected.len() == actual.len(),
+        "streams changed, regenerate with UPDATE_FINGERPRINTS=1 if that's deliberate:\n{}",
+        changed
+            .iter()
+            .map(|line| line.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
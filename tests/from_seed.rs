@@ -0,0 +1,65 @@
+mod common;
+
+use common::for_each_generator;
+use prng_benchmark::*;
+
+fn assert_from_seed<R: SeedableRandom + Default>(from_seed: fn(u64) -> R) {
+    for seed in [0, 1, 42, u64::MAX] {
+        let expected = R::default();
+        expected.seed_from_u64(seed);
+        let rng = from_seed(seed);
+        for _ in 0..100 {
+            assert_eq!(rng.u64(), expected.u64(), "{}", std::any::type_name::<R>());
+        }
+    }
+}
+
+#[test]
+fn from_seed_matches_seed_from_u64() {
+    for_each_generator!(|_, R, from_seed| assert_from_seed::<R>(from_seed));
+}
+
+/// Zobrist keys for 12 kinds of chess pieces on 64 squares.
+const ZOBRIST: [[u64; 64]; 12] = {
+    let rng = Splitmix64::from_seed(0x2B0B);
+    let mut keys = [[0; 64]; 12];
+    let mut piece = 0;
+    while piece < 12 {
+        let mut square = 0;
+        while square < 64 {
+            keys[piece][square] = rng.next_u64();
+            square += 1;
+        }
+        piece += 1;
+    }
+    keys
+};
+
+#[test]
+fn tables_built_at_compile_time_match_the_stream() {
+    let rng = Splitmix64::from_seed(0x2B0B);
+    assert!(ZOBRIST.iter().flatten().all(|&key| key == rng.u64()));
+
+    const WYRAND: [u64; 4] = {
+        let rng = Wyrand::from_seed(7);
+        [
+            rng.next_u64(),
+            rng.next_u64(),
+            rng.next_u64(),
+            rng.next_u64(),
+        ]
+    };
+    let rng = Wyrand::from_seed(7);
+    assert_eq!(WYRAND, [rng.u64(), rng.u64(), rng.u64(), rng.u64()]);
+
+    const SFC: [u64; 2] = {
+        let rng = Sfc64::new(1);
+        [rng.next_u64(), rng.next_u64()]
+    };
+    let rng = Sfc64::new(1);
+    assert_eq!(SFC, [rng.u64(), rng.u64()]);
+
+    let rng = const { Mt19937_64::new(Mt19937_64::DEFAULT_SEED) };
+    let expected = Mt19937_64::new(Mt19937_64::DEFAULT_SEED);
+    assert_eq!(rng.u64(), expected.u64());
+}
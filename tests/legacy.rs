@@ -0,0 +1,42 @@
+use std::cell::Cell;
+
+use prng_benchmark::{Mt19937_64, Random, Xorshift128plus};
+
+#[test]
+fn mt19937_64_first_output() {
+    let rng = Mt19937_64::new(Mt19937_64::DEFAULT_SEED);
+    assert_eq!(rng.u64(), 14514284786278117030);
+}
+
+#[test]
+fn mt19937_64_10000th_output() {
+    // Required by the C++ standard for a default constructed `std::mt19937_64`.
+    let rng = Mt19937_64::new(Mt19937_64::DEFAULT_SEED);
+    for _ in 0..9999 {
+        rng.u64();
+    }
+    assert_eq!(rng.u64(), 9981545732273789042);
+}
+
+#[test]
+fn xorshift128plus_reference() {
+    // The expected values were produced with the reference implementation of the
+    // original paper, seeded with the state words 1 and 2.
+    let rng = Xorshift128plus {
+        s0: Cell::new(1),
+        s1: Cell::new(2),
+    };
+    let expected: [u64; 8] = [
+        8388677,
+        33554692,
+        70368777736387,
+        211106267148357,
+        281509366091972,
+        360777324180299,
+        288538377073858266,
+        865509272901433454,
+    ];
+    for x in expected {
+        assert_eq!(rng.u64(), x);
+    }
+}
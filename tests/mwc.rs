@@ -0,0 +1,124 @@
+use prng_benchmark::{
+    Mwc, Mwc128, Mwc128XXA32, Mwc192, Mwc256, Mwc256XXA64, Mwc32, Random, Random32,
+};
+
+// The expected values were produced with the reference implementations of
+// Sebastiano Vigna and Tom Kaitchuck.
+
+fn assert_stream(rng: &impl Random, expected: &[u64]) {
+    for &x in expected {
+        assert_eq!(rng.u64(), x);
+    }
+}
+
+#[test]
+fn mwc128_reference() {
+    let rng = Mwc128 {
+        mwc: Mwc::from_state([0x0123456789ABCDEF], 1).unwrap(),
+    };
+    assert_stream(
+        &rng,
+        &[
+            81985529216486895,
+            15419395148147905400,
+            10232899489506576004,
+            17233793922448098666,
+            1369659822029284051,
+            2233363045459645870,
+        ],
+    );
+}
+
+#[test]
+fn mwc192_reference() {
+    let rng = Mwc192 {
+        mwc: Mwc::from_state([0x0123456789ABCDEF, 0xFEDCBA9876543210], 1).unwrap(),
+    };
+    assert_stream(
+        &rng,
+        &[
+            18364758544493064720,
+            14950217113403694619,
+            3605328167135164703,
+            11108999731871463911,
+            13126480323506201751,
+            12946650184950258942,
+        ],
+    );
+}
+
+#[test]
+fn mwc256_reference() {
+    let rng = Mwc256 {
+        mwc: Mwc::from_state(
+            [0x0123456789ABCDEF, 0xFEDCBA9876543210, 0x0F1E2D3C4B5A6978],
+            1,
+        )
+        .unwrap(),
+    };
+    assert_stream(
+        &rng,
+        &[
+            1089357896855742840,
+            3856281847190923618,
+            14675200793824711775,
+            2223100990259016518,
+            517290917454531253,
+            18148543081144933771,
+        ],
+    );
+}
+
+#[test]
+fn mwc256xxa64_reference() {
+    let rng = Mwc256XXA64 {
+        mwc: Mwc::from_state(
+            [0xCAFEF00DD15EA5E5, 0xFEDCBA9876543210, 0x0123456789ABCDEF],
+            0x14057B7EF767814F,
+        )
+        .unwrap(),
+    };
+    assert_stream(
+        &rng,
+        &[
+            18227941680432852838,
+            15756051015844504399,
+            5486756983445538014,
+            9710282903290637246,
+            606302979121353389,
+            327808095853305933,
+        ],
+    );
+}
+
+#[test]
+fn mwc128xxa32_reference() {
+    let rng = Mwc128XXA32 {
+        mwc: Mwc32::from_state([0xCAFEF00D, 0x89ABCDEF, 0x01234567], 0x14057B7E).unwrap(),
+    };
+    let expected: [u32; 6] = [
+        3913706465, 2564701344, 2468021648, 1364767044, 2500623204, 3512924746,
+    ];
+    for x in expected {
+        assert_eq!(rng.u32(), x);
+    }
+}
+
+#[test]
+fn mwc_carry_validation() {
+    const A: u64 = 0xFFEBB71D94FCDAF9;
+
+    assert!(Mwc::<1, A>::from_state([0], 0).is_none());
+    assert!(Mwc::<1, A>::from_state([1], A - 1).is_none());
+    assert!(Mwc::<1, A>::from_state([u64::MAX], A - 1).is_none());
+    assert!(Mwc::<1, A>::from_state([0], 1).is_some());
+    assert!(Mwc::<1, A>::from_state([u64::MAX], A - 2).is_some());
+}
+
+#[test]
+fn mwc_seed_is_valid() {
+    for _ in 0..100 {
+        assert!(Mwc256::default().mwc.is_valid());
+        assert!(Mwc128XXA32::default().mwc.is_valid());
+    }
+}
@@ -0,0 +1,112 @@
+//! Property tests for the methods `Random` provides on top of `u64()`.
+
+mod common;
+
+use common::for_each_generator;
+use prng_benchmark::*;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+fn seeded<R: SeedableRandom + Default>(seed: u64) -> R {
+    let rng = R::default();
+    rng.seed_from_u64(seed);
+    rng
+}
+
+/// What `fill_bytes()` documents to write: full words, then the lowest byte of
+/// one output per remaining byte.
+fn expected_bytes(rng: &impl Random, len: usize) -> Vec<u8> {
+    let mut bytes: Vec<u8> = (0..len / 8).flat_map(|_| rng.u64().to_ne_bytes()).collect();
+    bytes.extend((0..len % 8).map(|_| rng.u64() as u8));
+    bytes
+}
+
+fn fill(rng: &impl Random, len: usize) -> Vec<u8> {
+    let mut bytes = vec![0; len];
+    rng.fill_bytes(&mut bytes);
+    bytes
+}
+
+fn check_fill_bytes<R: SeedableRandom + Default>(
+    seed: u64,
+    first: usize,
+    second: usize,
+) -> Result<(), TestCaseError> {
+    let name = std::any::type_name::<R>();
+
+    let rng = seeded::<R>(seed);
+    let pieces = [fill(&rng, first), fill(&rng, second)].concat();
+    prop_assert_eq!(
+        &fill(&seeded::<R>(seed), first),
+        &pieces[..first],
+        "{}",
+        name
+    );
+
+    let reference = seeded::<R>(seed);
+    let expected = [
+        expected_bytes(&reference, first),
+        expected_bytes(&reference, second),
+    ]
+    .concat();
+    prop_assert_eq!(&pieces, &expected, "{}", name);
+    // Both continue with the same output afterwards.
+    prop_assert_eq!(rng.u64(), reference.u64(), "{}", name);
+
+    // Pieces of whole words are seamless.
+    let whole = first / 8 * 8;
+    let rng = seeded::<R>(seed);
+    let pieces = [fill(&rng, whole), fill(&rng, second)].concat();
+    prop_assert_eq!(pieces, fill(&seeded::<R>(seed), whole + second), "{}", name);
+    Ok(())
+}
+
+/// Checks that the first outputs are not all the same, which would mean the
+/// state is a fixed point of the generator, like all zeros for xoshiro.
+fn check_not_stuck<R: Random>(rng: &R) -> Result<(), TestCaseError> {
+    let outputs: Vec<u64> = (0..16).map(|_| rng.u64()).collect();
+    prop_assert!(
+        outputs.iter().any(|&x| x != outputs[0]),
+        "{} repeats {:#x}",
+        std::any::type_name::<R>(),
+        outputs[0]
+    );
+    Ok(())
+}
+
+fn check_seed_from<R: SeedableRandom + Default>(words: &[u64]) -> Result<(), TestCaseError> {
+    let rng = R::default();
+    let mut words = words.iter().copied();
+    rng.seed_from(|| words.next().unwrap());
+    check_not_stuck(&rng)
+}
+
+fn check_seed<R: SeedableRandom + Default>() -> Result<(), TestCaseError> {
+    check_not_stuck(&R::default())?;
+    let rng = seeded::<R>(0);
+    rng.seed();
+    check_not_stuck(&rng)
+}
+
+/// Seed words that favour the values generators have to fix up.
+fn seed_word() -> impl Strategy<Value = u64> {
+    prop_oneof![Just(0), Just(u64::MAX), Just(1), any::<u64>()]
+}
+
+proptest! {
+    #[test]
+    fn fill_bytes_follows_the_stream(seed: u64, first in 0..80usize, second in 0..80usize) {
+        for_each_generator!(|_, R| check_fill_bytes::<R>(seed, first, second)?);
+    }
+
+    #[test]
+    fn seed_from_never_gets_stuck(words in vec(seed_word(), 16)) {
+        for_each_generator!(|_, R| check_seed_from::<R>(&words)?);
+    }
+}
+
+#[test]
+fn seed_never_gets_stuck() -> Result<(), TestCaseError> {
+    for_each_generator!(|_, R| check_seed::<R>()?);
+    Ok(())
+}
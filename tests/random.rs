@@ -0,0 +1,105 @@
+use std::cell::Cell;
+
+mod common;
+
+use common::for_each_generator;
+use prng_benchmark::*;
+
+/// Replays a fixed list of outputs.
+struct Replay<const N: usize> {
+    values: [u64; N],
+    index: Cell<usize>,
+}
+
+impl<const N: usize> Replay<N> {
+    fn new(values: [u64; N]) -> Self {
+        Self {
+            values,
+            index: Cell::new(0),
+        }
+    }
+}
+
+impl<const N: usize> Random for Replay<N> {
+    fn u64(&self) -> u64 {
+        let index = self.index.get();
+        self.index.set(index + 1);
+        self.values[index]
+    }
+
+    fn seed(&self) {}
+}
+
+#[test]
+fn f64_stays_in_unit_interval() {
+    assert_eq!(Replay::new([0]).f64(), 0.0);
+    assert_eq!(Replay::new([1 << 63]).f64(), 0.5);
+    assert!(Replay::new([u64::MAX]).f64() < 1.0);
+}
+
+#[test]
+fn bounded_u64_rejects_biased_samples() {
+    // 2^64 mod 3 == 1, so only a low product of 0 has to be rejected.
+    let rng = Replay::new([0, u64::MAX]);
+    assert_eq!(rng.bounded_u64(3), 2);
+    assert_eq!(rng.index.get(), 2);
+
+    let rng = Replay::new([u64::MAX / 3 + 1]);
+    assert_eq!(rng.bounded_u64(3), 1);
+    assert_eq!(rng.index.get(), 1);
+}
+
+#[test]
+#[should_panic(expected = "bound must not be 0")]
+fn bounded_u64_rejects_a_zero_bound() {
+    Replay::new([0]).bounded_u64(0);
+}
+
+#[test]
+fn bounded_u64_is_uniform() {
+    let rng = Xoshiro256plusplus::default();
+    let mut counts = [0u32; 6];
+    for _ in 0..60_000 {
+        counts[rng.bounded_u64(6) as usize] += 1;
+    }
+    // Each bucket expects 10,000 hits with a standard deviation of about 91.
+    for count in counts {
+        assert!((9_500..10_500).contains(&count), "{counts:?}");
+    }
+}
+
+#[test]
+fn random_works_as_a_trait_object() {
+    let rngs: [Box<dyn Random>; 2] = [
+        Box::new(Replay::new([1 << 63, 0])),
+        Box::new(Wyrand::from_seed(1)),
+    ];
+    assert_eq!(rngs[0].f64(), 0.5);
+    let mut bytes = [0; 3];
+    rngs[1].fill_bytes(&mut bytes);
+    rngs[1].seed();
+    assert!(rngs[1].bounded_u64(6) < 6);
+}
+
+fn assert_seeding<R: SeedableRandom + Default>() {
+    let rng = R::default();
+
+    let mut consumed = 0;
+    rng.seed_from(|| {
+        consumed += 1;
+        0x0123456789ABCDEF ^ consumed as u64
+    });
+    assert_eq!(consumed, R::SEED_WORDS, "{}", std::any::type_name::<R>());
+
+    let other = R::default();
+    rng.seed_from_u64(42);
+    other.seed_from_u64(42);
+    for _ in 0..64 {
+        assert_eq!(rng.u64(), other.u64(), "{}", std::any::type_name::<R>());
+    }
+}
+
+#[test]
+fn seeding_consumes_seed_words_and_is_deterministic() {
+    for_each_generator!(|_, R| assert_seeding::<R>());
+}
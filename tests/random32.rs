@@ -0,0 +1,83 @@
+use std::cell::Cell;
+
+use prng_benchmark::{
+    Pcg32, Random, Random32, RomuTrio32, SeedableRandom, SeedableRandom32, Sfc32, Widen,
+    Xoshiro128plusplus,
+};
+
+// The expected values were produced with the reference implementations of
+// Melissa O'Neill, Sebastiano Vigna, Chris Doty-Humphrey and Mark Overton.
+
+fn assert_stream(rng: &impl Random32, expected: &[u32]) {
+    for &x in expected {
+        assert_eq!(rng.u32(), x);
+    }
+}
+
+#[test]
+fn pcg32_reference() {
+    assert_stream(
+        &Pcg32::new(42, 54),
+        &[
+            0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e,
+        ],
+    );
+}
+
+#[test]
+fn xoshiro128plusplus_reference() {
+    let [s0, s1, s2, s3] = [1, 2, 3, 4].map(Cell::new);
+    assert_stream(
+        &Xoshiro128plusplus { s0, s1, s2, s3 },
+        &[
+            641, 1573767, 3222811527, 3517856514, 836907274, 4247214768, 3867114732, 1355841295,
+            495546011, 621204420,
+        ],
+    );
+}
+
+#[test]
+fn sfc32_reference() {
+    assert_stream(
+        &Sfc32::new(0x0123456789ABCDEF),
+        &[
+            2222009751, 4121156040, 1557176981, 903895892, 1281522849, 866141154,
+        ],
+    );
+}
+
+#[test]
+fn romu_trio32_reference() {
+    let [x, y, z] = [1, 2, 3].map(Cell::new);
+    assert_stream(
+        &RomuTrio32 { x, y, z },
+        &[
+            1, 1381512577, 3401580544, 1616201941, 1232173000, 1326144886,
+        ],
+    );
+}
+
+#[test]
+fn widen_composes_two_outputs() {
+    let narrow = Pcg32::new(42, 54);
+    let wide = Widen(Pcg32::new(42, 54));
+    for _ in 0..8 {
+        let lo = narrow.u32() as u64;
+        let hi = narrow.u32() as u64;
+        assert_eq!(wide.u64(), (hi << 32) | lo);
+    }
+}
+
+#[test]
+fn widen_seeds_like_the_wrapped_generator() {
+    let narrow = Sfc32::default();
+    let wide = Widen(Sfc32::default());
+    narrow.seed_from_u64(7);
+    wide.seed_from_u64(7);
+    assert_eq!(Widen::<Sfc32>::SEED_WORDS, Sfc32::SEED_WORDS);
+    for _ in 0..8 {
+        let lo = narrow.u32() as u64;
+        let hi = narrow.u32() as u64;
+        assert_eq!(wide.u64(), (hi << 32) | lo);
+    }
+}
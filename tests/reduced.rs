@@ -0,0 +1,183 @@
+use std::cell::Cell;
+
+use prng_benchmark::reduced::*;
+use prng_benchmark::{
+    Mwc128XXA32, Mwc256XXA64, Random, RomuJr, RomuTrio, RomuTrio32, SeedableRandom, Tylo64,
+};
+
+/// Checks that outputs fit the width and that `fill_raw()` writes them as
+/// little-endian words.
+fn assert_raw_output<R: ReducedRandom + Default>() {
+    let name = std::any::type_name::<R>();
+    let size = R::BITS as usize / 8;
+
+    let rng = R::default();
+    rng.seed_from_u64(0x5CA1E);
+    let words: Vec<u64> = (0..1000).map(|_| rng.word()).collect();
+    assert!(
+        words.iter().all(|w| R::BITS == 64 || w >> R::BITS == 0),
+        "{name}: output wider than {} bits",
+        R::BITS
+    );
+    assert!(
+        words.iter().any(|w| w >> (R::BITS - 1) != 0),
+        "{name}: top bit never set"
+    );
+
+    rng.seed_from_u64(0x5CA1E);
+    let mut bytes = vec![0; 1000 * size];
+    rng.fill_raw(&mut bytes);
+    for (word, chunk) in words.iter().zip(bytes.chunks_exact(size)) {
+        assert_eq!(&word.to_le_bytes()[..size], chunk, "{name}");
+    }
+}
+
+#[test]
+fn raw_output_matches_words() {
+    assert_raw_output::<RomuJr8>();
+    assert_raw_output::<RomuJr16>();
+    assert_raw_output::<RomuJr32>();
+    assert_raw_output::<RomuJr>();
+    assert_raw_output::<RomuTrio8>();
+    assert_raw_output::<RomuTrio16>();
+    assert_raw_output::<RomuTrio32>();
+    assert_raw_output::<RomuTrio>();
+    assert_raw_output::<Tylo8>();
+    assert_raw_output::<Tylo16>();
+    assert_raw_output::<Tylo32>();
+    assert_raw_output::<Tylo64>();
+    assert_raw_output::<Mwc32XXA8>();
+    assert_raw_output::<Mwc64XXA16>();
+    assert_raw_output::<Mwc128XXA32>();
+    assert_raw_output::<Mwc256XXA64>();
+}
+
+#[test]
+fn full_width_generators_keep_their_output() {
+    let reduced = Tylo64::default();
+    let regular = Tylo64::default();
+    ReducedRandom::seed_from_u64(&reduced, 3);
+    SeedableRandom::seed_from_u64(&regular, 3);
+    for _ in 0..100 {
+        assert_eq!(reduced.word(), regular.u64());
+    }
+}
+
+#[test]
+#[should_panic(expected = "multiple of the word size")]
+fn fill_raw_rejects_partial_words() {
+    Tylo32::default().fill_raw(&mut [0; 6]);
+}
+
+#[test]
+fn mwc_states_stay_valid() {
+    let rng = Mwc32XXA8::default();
+    for seed in 0..100 {
+        rng.seed_from_u64(seed);
+        assert!(rng.mwc.is_valid());
+        for _ in 0..1000 {
+            rng.word();
+        }
+        assert!(rng.mwc.is_valid());
+    }
+
+    let rng = Mwc64XXA16::default();
+    rng.seed_from_u64(7);
+    for _ in 0..100_000 {
+        rng.word();
+    }
+    assert!(rng.mwc.is_valid());
+}
+
+/// Counts down to 9, then cycles through 0..10.
+#[derive(Default)]
+struct Rho(Cell<u8>);
+
+impl ReducedRandom for Rho {
+    const BITS: u32 = 8;
+    const SEED_WORDS: usize = 1;
+
+    fn word(&self) -> u64 {
+        let s = self.0.get();
+        self.0.set(if s >= 10 { s - 1 } else { (s + 1) % 10 });
+        s as u64
+    }
+
+    fn seed_from(&self, mut next: impl FnMut() -> u64) {
+        self.0.set(next() as u8);
+    }
+}
+
+impl SmallState for Rho {
+    const STATE_BITS: u32 = 8;
+
+    fn state(&self) -> u128 {
+        self.0.get() as u128
+    }
+
+    fn set_state(&self, state: u128) {
+        self.0.set(state as u8);
+    }
+}
+
+#[test]
+fn find_cycle_measures_tail_and_length() {
+    let rng = Rho(Cell::new(15));
+    assert_eq!(
+        find_cycle(&rng, 100),
+        Some(Cycle {
+            tail: 6,
+            length: 10
+        })
+    );
+
+    rng.0.set(3);
+    assert_eq!(
+        find_cycle(&rng, 10),
+        Some(Cycle {
+            tail: 0,
+            length: 10
+        })
+    );
+    rng.0.set(3);
+    assert_eq!(find_cycle(&rng, 9), None);
+    rng.0.set(200);
+    assert_eq!(find_cycle(&rng, 100), None);
+}
+
+#[test]
+fn found_cycles_close() {
+    let rng = RomuJr8::default();
+    for seed in 0..20 {
+        rng.seed_from_u64(seed);
+        let cycle = find_cycle(&rng, 1 << 20).unwrap();
+
+        let start = rng.state();
+        for _ in 0..cycle.length {
+            rng.word();
+        }
+        assert_eq!(rng.state(), start);
+    }
+}
+
+#[test]
+fn states_round_trip() {
+    fn assert_round_trip<R: SmallState + Default>() {
+        let rng = R::default();
+        let state = rng.state();
+        assert!(R::STATE_BITS == 128 || state >> R::STATE_BITS == 0);
+
+        let expected: Vec<u64> = (0..10).map(|_| rng.word()).collect();
+        rng.set_state(state);
+        assert_eq!(rng.state(), state);
+        assert!((0..10).map(|_| rng.word()).eq(expected));
+    }
+
+    assert_round_trip::<RomuJr8>();
+    assert_round_trip::<RomuJr32>();
+    assert_round_trip::<RomuTrio16>();
+    assert_round_trip::<Tylo8>();
+    assert_round_trip::<Tylo32>();
+    assert_round_trip::<Mwc32XXA8>();
+    assert_round_trip::<Mwc64XXA16>();
+}
@@ -0,0 +1,36 @@
+use prng_benchmark::*;
+
+/// Walks forwards and back again, which must replay the same outputs in
+/// reverse and restore the state.
+fn assert_round_trip<R: ReversibleRandom + SeedableRandom + Default>() {
+    let rng = R::default();
+    rng.seed_from_u64(0xBAC4);
+    let name = std::any::type_name::<R>();
+
+    let forwards: Vec<u64> = (0..1000).map(|_| rng.u64()).collect();
+    let backwards: Vec<u64> = (0..1000).map(|_| rng.prev_u64()).collect();
+    assert!(
+        forwards.iter().eq(backwards.iter().rev()),
+        "{name}: backwards differs"
+    );
+    assert_eq!(rng.u64(), forwards[0], "{name}: state not restored");
+
+    // Stepping back past the seeded state and forwards again.
+    rng.seed_from_u64(0xBAC4);
+    let before: Vec<u64> = (0..10).map(|_| rng.prev_u64()).collect();
+    let after: Vec<u64> = (0..10).map(|_| rng.u64()).collect();
+    assert!(
+        before.iter().eq(after.iter().rev()),
+        "{name}: rewind differs"
+    );
+    assert_eq!(rng.u64(), forwards[0], "{name}: state not restored");
+}
+
+#[test]
+fn reversible_generators_round_trip() {
+    assert_round_trip::<Lehmer64>();
+    assert_round_trip::<Pcg64Fast>();
+    assert_round_trip::<Pcg64>();
+    assert_round_trip::<Splitmix64>();
+    assert_round_trip::<Xoshiro256plusplus>();
+}
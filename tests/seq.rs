@@ -0,0 +1,106 @@
+mod common;
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use common::{assert_chi_square_at, for_each_generator, Z_0_00001};
+use prng_benchmark::*;
+
+const SAMPLES: usize = 12_000;
+
+/// Asserts that `outcome` hits all of `expected` outcomes equally often.
+fn assert_uniform<T: Hash + Eq>(name: &str, expected: usize, mut outcome: impl FnMut() -> T) {
+    let mut counts = HashMap::new();
+    for _ in 0..SAMPLES {
+        *counts.entry(outcome()).or_insert(0) += 1;
+    }
+    assert_eq!(counts.len(), expected, "{name}: missing outcomes");
+
+    let observed: Vec<u64> = counts.into_values().collect();
+    // Runs for every generator, so a stricter alpha keeps false alarms rare.
+    let probabilities = vec![1.0 / expected as f64; expected];
+    assert_chi_square_at(name, &observed, &probabilities, Z_0_00001);
+}
+
+fn assert_permutations<R: SeedableRandom + Default>() {
+    let rng = R::default();
+    rng.seed_from_u64(0x5E9);
+    let name = std::any::type_name::<R>();
+
+    assert_uniform(&format!("shuffle with {name}"), 24, || {
+        let mut slice = [0, 1, 2, 3];
+        seq::shuffle(&rng, &mut slice);
+        slice
+    });
+
+    // Ordered pairs out of 5.
+    assert_uniform(&format!("partial_shuffle with {name}"), 20, || {
+        let mut slice = [0, 1, 2, 3, 4];
+        let (chosen, _) = seq::partial_shuffle(&rng, &mut slice, 2);
+        chosen.to_vec()
+    });
+
+    // Ordered triples out of 6.
+    assert_uniform(&format!("choose_multiple with {name}"), 120, || {
+        seq::choose_multiple(&rng, &[0, 1, 2, 3, 4, 5], 3)
+            .into_iter()
+            .copied()
+            .collect::<Vec<_>>()
+    });
+
+    assert_uniform(&format!("choose with {name}"), 7, || {
+        *seq::choose(&rng, &[0, 1, 2, 3, 4, 5, 6]).unwrap()
+    });
+
+    assert_uniform(&format!("choose_iter with {name}"), 7, || {
+        seq::choose_iter(&rng, 0..7).unwrap()
+    });
+
+    // Unordered pairs out of 6.
+    assert_uniform(&format!("reservoir_sample with {name}"), 15, || {
+        let mut sample = seq::reservoir_sample(&rng, 0..6, 2);
+        sample.sort();
+        sample
+    });
+}
+
+#[test]
+fn permutations_are_uniform_for_every_generator() {
+    for_each_generator!(|_, R| assert_permutations::<R>());
+}
+
+#[test]
+fn edge_cases() {
+    let rng = Xoshiro256plusplus::default();
+
+    let mut empty: [u8; 0] = [];
+    seq::shuffle(&rng, &mut empty);
+    assert!(seq::choose(&rng, &empty).is_none());
+    assert!(seq::choose_iter(&rng, 0..0).is_none());
+
+    let mut slice = [1, 2, 3];
+    let (chosen, rest) = seq::partial_shuffle(&rng, &mut slice, 10);
+    assert_eq!(chosen.len(), 3);
+    assert!(rest.is_empty());
+
+    assert_eq!(seq::choose_multiple(&rng, &[1, 2, 3], 0).len(), 0);
+    let mut all = seq::sample_indices(&rng, 5, 8);
+    all.sort();
+    assert_eq!(all, [0, 1, 2, 3, 4]);
+
+    assert_eq!(seq::reservoir_sample(&rng, 0..2, 5), [0, 1]);
+    assert!(seq::reservoir_sample(&rng, 0..2, 0).is_empty());
+}
+
+#[test]
+fn sample_indices_are_distinct() {
+    let rng = Pcg64Dxsm::default();
+    for (len, amount) in [(10, 10), (1000, 999), (1 << 40, 500)] {
+        let mut indices = seq::sample_indices(&rng, len, amount);
+        assert_eq!(indices.len(), amount);
+        assert!(indices.iter().all(|&i| i < len));
+        indices.sort();
+        indices.dedup();
+        assert_eq!(indices.len(), amount);
+    }
+}
@@ -0,0 +1,155 @@
+mod common;
+
+use common::assert_chi_square;
+use prng_benchmark::distributions::*;
+use prng_benchmark::{ChaCha8, Pcg64Dxsm, Random, SeedableRandom, Xoshiro256plusplus};
+
+const SAMPLES: usize = 100_000;
+
+const WEIGHTS: [f64; 8] = [1.0, 0.0, 7.5, 3.0, 0.25, 12.0, 0.0, 4.25];
+
+/// Samples with several generators and compares the histogram with `weights`.
+fn assert_fits<D: Distribution<usize>>(name: &str, distribution: &D, weights: &[f64]) {
+    fn check<R: SeedableRandom, D: Distribution<usize>>(
+        name: &str,
+        rng: R,
+        distribution: &D,
+        probabilities: &[f64],
+    ) {
+        rng.seed_from_u64(0x3E16_47ED);
+        let mut observed = vec![0; probabilities.len()];
+        for _ in 0..SAMPLES {
+            observed[distribution.sample(&rng)] += 1;
+        }
+        for (i, _) in probabilities.iter().enumerate().filter(|(_, p)| **p == 0.0) {
+            assert_eq!(observed[i], 0, "{name} sampled index {i} with weight 0");
+        }
+        assert_chi_square(
+            &format!("{name} with {}", std::any::type_name::<R>()),
+            &observed,
+            probabilities,
+        );
+    }
+
+    let total: f64 = weights.iter().sum();
+    let probabilities: Vec<f64> = weights.iter().map(|w| w / total).collect();
+
+    check(
+        name,
+        Xoshiro256plusplus::default(),
+        distribution,
+        &probabilities,
+    );
+    check(name, Pcg64Dxsm::default(), distribution, &probabilities);
+    check(name, ChaCha8::default(), distribution, &probabilities);
+}
+
+#[test]
+fn alias_table_fits() {
+    assert_fits("AliasTable", &AliasTable::new(&WEIGHTS).unwrap(), &WEIGHTS);
+
+    let integer: Vec<u64> = WEIGHTS.iter().map(|w| (w * 4.0) as u64).collect();
+    assert_fits(
+        "AliasTable",
+        &AliasTable::from_integer_weights(&integer).unwrap(),
+        &WEIGHTS,
+    );
+
+    // Many columns with a skewed distribution.
+    let skewed: Vec<f64> = (1..=200).map(|i| 1.0 / i as f64).collect();
+    assert_fits("AliasTable", &AliasTable::new(&skewed).unwrap(), &skewed);
+}
+
+#[test]
+fn cumulative_weights_fit() {
+    assert_fits(
+        "CumulativeWeights",
+        &CumulativeWeights::new(&WEIGHTS).unwrap(),
+        &WEIGHTS,
+    );
+}
+
+#[test]
+fn cumulative_weights_can_be_updated() {
+    let mut weights = WEIGHTS.to_vec();
+    let mut cumulative = CumulativeWeights::new(&weights).unwrap();
+
+    assert!(cumulative.update(5, 0.0));
+    assert!(cumulative.update(1, 2.0));
+    assert!(cumulative.push(6.0));
+    assert!(!cumulative.push(-1.0));
+    assert!(!cumulative.update(0, f64::NAN));
+    weights[5] = 0.0;
+    weights[1] = 2.0;
+    weights.push(6.0);
+
+    assert_eq!(cumulative.len(), weights.len());
+    assert_eq!(cumulative.weight(5), 0.0);
+    assert_fits("CumulativeWeights", &cumulative, &weights);
+
+    let mut single = CumulativeWeights::new(&[1.0]).unwrap();
+    assert!(!single.update(0, 0.0));
+}
+
+#[test]
+fn updates_keep_later_zero_weights_exact() {
+    // Shifting the entry after the update by the difference would leave it
+    // 1.1e-16 above the updated one.
+    let mut cumulative = CumulativeWeights::new(&[0.1, 0.1, 0.0]).unwrap();
+    assert!(cumulative.update(1, 0.7));
+    assert_eq!(cumulative.weight(2), 0.0);
+    assert_eq!(cumulative.weight(1), 0.7);
+    assert_fits("CumulativeWeights", &cumulative, &[0.1, 0.7, 0.0]);
+}
+
+#[test]
+#[should_panic]
+fn update_rejects_an_out_of_range_index() {
+    CumulativeWeights::new(&[1.0]).unwrap().update(1, 1.0);
+}
+
+#[test]
+fn alias_table_thresholds_are_exact() {
+    // With weights 1 and 3 the first column keeps index 0 with probability 1/2.
+    struct Fixed([u64; 2], std::cell::Cell<usize>);
+
+    impl Random for Fixed {
+        fn u64(&self) -> u64 {
+            let i = self.1.get();
+            self.1.set(i + 1);
+            self.0[i % 2]
+        }
+
+        fn seed(&self) {}
+    }
+
+    let table = AliasTable::from_integer_weights(&[1, 3]).unwrap();
+    let sample = |u| table.sample(&Fixed([0, u], Default::default()));
+    assert_eq!(sample((1 << 63) - 1), 0);
+    assert_eq!(sample(1 << 63), 1);
+    assert_eq!(sample(u64::MAX), 1);
+}
+
+#[test]
+fn single_weight_is_always_sampled() {
+    let rng = Xoshiro256plusplus::default();
+    let table = AliasTable::new(&[0.0, 0.5, 0.0]).unwrap();
+    for _ in 0..10_000 {
+        assert_eq!(table.sample(&rng), 1);
+    }
+}
+
+#[test]
+fn invalid_weights_are_rejected() {
+    assert!(AliasTable::new(&[]).is_none());
+    assert!(AliasTable::new(&[0.0, 0.0]).is_none());
+    assert!(AliasTable::new(&[1.0, -1.0]).is_none());
+    assert!(AliasTable::new(&[1.0, f64::NAN]).is_none());
+    assert!(AliasTable::new(&[f64::MAX, f64::MAX]).is_none());
+    assert!(AliasTable::from_integer_weights(&[]).is_none());
+    assert!(AliasTable::from_integer_weights(&[0]).is_none());
+    assert!(AliasTable::from_integer_weights(&[u64::MAX, 1]).is_none());
+    assert!(CumulativeWeights::new(&[]).is_none());
+    assert!(CumulativeWeights::new(&[0.0]).is_none());
+    assert!(CumulativeWeights::new(&[f64::INFINITY]).is_none());
+}
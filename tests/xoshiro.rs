@@ -0,0 +1,231 @@
+use std::cell::Cell;
+
+use prng_benchmark::{
+    Random, Xoroshiro1024plusplus, Xoroshiro128plus, Xoroshiro128plusplus, Xoroshiro128starstar,
+    Xoshiro256plus, Xoshiro256plusplus, Xoshiro256starstar, Xoshiro512plusplus,
+};
+
+// The expected values were produced with the reference implementations from
+// <https://prng.di.unimi.it/>, seeded with the state words 1, 2, 3, ...
+
+fn assert_stream(rng: &impl Random, expected: &[u64]) {
+    for &x in expected {
+        assert_eq!(rng.u64(), x);
+    }
+}
+
+#[test]
+fn xoshiro256plusplus_reference() {
+    let [s0, s1, s2, s3] = [1, 2, 3, 4].map(Cell::new);
+    let rng = Xoshiro256plusplus { s0, s1, s2, s3 };
+    assert_stream(
+        &rng,
+        &[
+            41943041,
+            58720359,
+            3588806011781223,
+            3591011842654386,
+            9228616714210784205,
+            9973669472204895162,
+            14011001112246962877,
+            12406186145184390807,
+            15849039046786891736,
+            10450023813501588000,
+        ],
+    );
+}
+
+#[test]
+fn xoshiro256plusplus_jump() {
+    let [s0, s1, s2, s3] = [1, 2, 3, 4].map(Cell::new);
+    let rng = Xoshiro256plusplus { s0, s1, s2, s3 };
+    rng.jump();
+    assert_stream(
+        &rng,
+        &[
+            17043750140134683703,
+            2364973248208838314,
+            13951431646535487319,
+            8066193832155293345,
+        ],
+    );
+}
+
+#[test]
+fn xoshiro256starstar_reference() {
+    let [s0, s1, s2, s3] = [1, 2, 3, 4].map(Cell::new);
+    let rng = Xoshiro256starstar { s0, s1, s2, s3 };
+    assert_stream(
+        &rng,
+        &[
+            11520,
+            0,
+            1509978240,
+            1215971899390074240,
+            1216172134540287360,
+            607988272756665600,
+            16172922978634559625,
+            8476171486693032832,
+            10595114339597558777,
+            2904607092377533576,
+        ],
+    );
+}
+
+#[test]
+fn xoshiro256plus_reference() {
+    let [s0, s1, s2, s3] = [1, 2, 3, 4].map(Cell::new);
+    let rng = Xoshiro256plus { s0, s1, s2, s3 };
+    assert_stream(
+        &rng,
+        &[
+            5,
+            211106232532999,
+            211106635186183,
+            9223759065350669058,
+            9250833439874351877,
+            13862484359527728515,
+            2346507365006083650,
+            1168864526675804870,
+            34095955243042024,
+            3466914240207415127,
+        ],
+    );
+}
+
+#[test]
+fn xoshiro512plusplus_reference() {
+    let rng = Xoshiro512plusplus {
+        s: [1, 2, 3, 4, 5, 6, 7, 8].map(Cell::new),
+    };
+    assert_stream(
+        &rng,
+        &[
+            524291,
+            1048578,
+            539099140,
+            3299073855497,
+            6917532603230064654,
+            7494048333530275843,
+            14418333309547923463,
+            10960079161595355914,
+            18279570946505382726,
+            10209173166699159237,
+        ],
+    );
+}
+
+#[test]
+fn xoroshiro128plusplus_reference() {
+    let rng = Xoroshiro128plusplus {
+        s0: Cell::new(1),
+        s1: Cell::new(2),
+    };
+    assert_stream(
+        &rng,
+        &[
+            393217,
+            669327710093319,
+            1732421326133921491,
+            11394790081659126983,
+            9555452776773192676,
+            3586421180005889563,
+            1691397964866707553,
+            10735626796753111697,
+            15216282715349408991,
+            14247243556711267923,
+        ],
+    );
+}
+
+#[test]
+fn xoroshiro128starstar_reference() {
+    let rng = Xoroshiro128starstar {
+        s0: Cell::new(1),
+        s1: Cell::new(2),
+    };
+    assert_stream(
+        &rng,
+        &[
+            5760,
+            97769243520,
+            9706862127477703552,
+            9223447511460779954,
+            8358291023205304566,
+            15695619998649302768,
+            8517900938696309774,
+            16586480348202605369,
+            6959129367028440372,
+            16822147227405758281,
+        ],
+    );
+}
+
+#[test]
+fn xoroshiro128plus_reference() {
+    let rng = Xoroshiro128plus {
+        s0: Cell::new(1),
+        s1: Cell::new(2),
+    };
+    assert_stream(
+        &rng,
+        &[
+            3,
+            412333834243,
+            2360170716294286339,
+            9295852285959843169,
+            2797080929874688578,
+            6019711933173041966,
+            3076529664176959358,
+            3521761819100106140,
+            7493067640054542992,
+            920801338098114767,
+        ],
+    );
+}
+
+fn xoroshiro1024plusplus() -> Xoroshiro1024plusplus {
+    Xoroshiro1024plusplus {
+        s: std::array::from_fn(|i| Cell::new(i as u64 + 1)),
+        p: Cell::new(0),
+    }
+}
+
+#[test]
+fn xoroshiro1024plusplus_reference() {
+    let rng = xoroshiro1024plusplus();
+    assert_stream(
+        &rng,
+        &[
+            25165825,
+            1729382463093866496,
+            1729382469544706816,
+            2305896067134128920,
+            2882358539580539928,
+            3472347753827474720,
+            4049248931463568936,
+            8088996010033751856,
+            8778205716228747320,
+            10512250523491973488,
+        ],
+    );
+}
+
+#[test]
+fn xoroshiro1024plusplus_jump() {
+    // Advance first, so the jump has to deal with a rotated ring buffer.
+    let rng = xoroshiro1024plusplus();
+    (0..5).for_each(|_| {
+        rng.u64();
+    });
+    rng.jump();
+    assert_stream(
+        &rng,
+        &[
+            116819532922478203,
+            10330023498990585384,
+            18322854933904212885,
+            5670349237729755734,
+        ],
+    );
+}